@@ -0,0 +1,10 @@
+#![no_main]
+
+use advent_of_code::y2025;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        y2025::fuzz(5, input);
+    }
+});
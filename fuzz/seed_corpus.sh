@@ -0,0 +1,23 @@
+#!/bin/sh
+# Seeds every fuzz target's corpus with the puzzle inputs for its day and a
+# few small generated ones. Run from anywhere; paths are relative to the repo.
+set -e
+cd "$(dirname "$0")/.."
+
+for day in 01 02 03 04 05 06 07 08; do
+    corpus="fuzz/corpus/day$day"
+    mkdir -p "$corpus"
+    for input in inputs/2025/day$day*.txt; do
+        [ -s "$input" ] && cp "$input" "$corpus/"
+    done
+    for seed in 1 2 3 4; do
+        cargo run -q --bin aoc -- gen "$day" --size 8 --seed "$seed" --out "$corpus/gen$seed.txt"
+    done
+done
+
+# Keep past crashes in the corpus so the fuzzers start from them.
+for input in inputs/regressions/fuzz/day*.txt; do
+    [ -e "$input" ] || continue
+    day=$(basename "$input" | cut -c4-5)
+    cp "$input" "fuzz/corpus/day$day/"
+done
@@ -0,0 +1,187 @@
+//! What a solver returns. Days answer with whatever integer type suits them,
+//! or with text, and answers are compared by their normalized text: plain
+//! decimal for every integer type, so an `i64` answer matches the same value
+//! computed as a `u128` or read from `fixtures.toml`.
+
+use crate::bigint::{BigInt, BigUint};
+use std::convert::Infallible;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Signed128(i128),
+    Unsigned128(u128),
+    Big(BigUint),
+    BigSigned(BigInt),
+    Text(String),
+}
+
+impl Answer {
+    /// The type the answer was computed in, for reports.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Answer::Signed(_) => "i64",
+            Answer::Unsigned(_) => "u64",
+            Answer::Signed128(_) => "i128",
+            Answer::Unsigned128(_) => "u128",
+            Answer::Big(_) => "BigUint",
+            Answer::BigSigned(_) => "BigInt",
+            Answer::Text(_) => "String",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => fmt::Display::fmt(n, f),
+            Answer::Unsigned(n) => fmt::Display::fmt(n, f),
+            Answer::Signed128(n) => fmt::Display::fmt(n, f),
+            Answer::Unsigned128(n) => fmt::Display::fmt(n, f),
+            Answer::Big(n) => fmt::Display::fmt(n, f),
+            Answer::BigSigned(n) => fmt::Display::fmt(n, f),
+            Answer::Text(s) => f.pad(s),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_string().hash(state);
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Reads an answer as written in the answers file: surrounding
+    /// whitespace is dropped and integers take the narrowest type that holds
+    /// them, so `+007` is the same answer as `7`. Anything else is text.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(n) = s.parse() {
+            return Ok(Answer::Signed(n));
+        }
+        if let Ok(n) = s.parse() {
+            return Ok(Answer::Unsigned(n));
+        }
+        if let Ok(n) = s.parse() {
+            return Ok(Answer::Signed128(n));
+        }
+        if let Ok(n) = s.parse() {
+            return Ok(Answer::Unsigned128(n));
+        }
+        if let Ok(n) = s.parse::<BigInt>() {
+            return Ok(match n.is_negative() {
+                true => Answer::BigSigned(n),
+                false => Answer::Big(n.magnitude().clone()),
+            });
+        }
+        Ok(Answer::Text(s.to_string()))
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident: $wide:ty, $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::$variant(n as $wide)
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(Signed: i64, i8, i16, i32, i64, isize);
+impl_from!(Unsigned: u64, u8, u16, u32, u64, usize);
+impl_from!(Signed128: i128, i128);
+impl_from!(Unsigned128: u128, u128);
+
+impl From<BigUint> for Answer {
+    fn from(n: BigUint) -> Self {
+        Answer::Big(n)
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        Answer::BigSigned(n)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Answer {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(matches!(parse(" -12\n"), Answer::Signed(-12)));
+        assert!(matches!(parse("+007"), Answer::Signed(7)));
+        assert!(matches!(parse(&u64::MAX.to_string()), Answer::Unsigned(_)));
+        assert!(matches!(
+            parse(&i128::MIN.to_string()),
+            Answer::Signed128(_)
+        ));
+        assert!(matches!(
+            parse(&u128::MAX.to_string()),
+            Answer::Unsigned128(_)
+        ));
+        assert!(matches!(
+            parse("+1000000000000000000000000000000000000000"),
+            Answer::Big(_)
+        ));
+        assert!(matches!(
+            parse("-1000000000000000000000000000000000000000"),
+            Answer::BigSigned(_)
+        ));
+        assert!(matches!(parse("1,2"), Answer::Text(_)));
+        assert_eq!(
+            parse("0001000000000000000000000000000000000000000").to_string(),
+            "1000000000000000000000000000000000000000"
+        );
+    }
+
+    #[test]
+    fn test_compare_across_types() {
+        assert_eq!(Answer::from(42usize), Answer::from(42i64));
+        assert_eq!(Answer::from(42u128), parse("42"));
+        assert_eq!(Answer::from(BigUint::from(42u64)), parse(" 042 "));
+        assert_eq!(Answer::from(BigInt::from(-42i64)), parse("-42"));
+        assert_ne!(Answer::from(-1i32), Answer::from(1u8));
+        assert_eq!(Answer::from("1,2"), parse("1,2"));
+        assert_eq!(Answer::from(7u32).type_name(), "u64");
+        assert_eq!(
+            format!("{:>4}|{:<4}|", Answer::from(7), Answer::from("ab")),
+            "   7|ab  |"
+        );
+    }
+}
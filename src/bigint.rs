@@ -0,0 +1,877 @@
+//! Arbitrary-precision integers for answers that outgrow `u128`: an
+//! unsigned [`BigUint`] and a signed [`BigInt`] built on it, with the usual
+//! arithmetic, decimal parsing and formatting, and [`Sum`]/[`Product`] so
+//! iterator code reads the same as with primitives.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use std::str::FromStr;
+
+/// Operands with at least this many limbs are multiplied by Karatsuba's
+/// method instead of limb by limb.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Arbitrary-precision unsigned integer, stored as little-endian base 2^32
+/// limbs with no trailing zero limbs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        Self { limbs: Vec::new() }
+    }
+
+    pub fn one() -> Self {
+        Self { limbs: vec![1] }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    fn from_limbs(limbs: Vec<u32>) -> Self {
+        let mut big = Self { limbs };
+        big.normalize();
+        big
+    }
+
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    /// The value as a `u128`, if it fits.
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.limbs.len() > 4 {
+            return None;
+        }
+        Some(
+            self.limbs
+                .iter()
+                .rev()
+                .fold(0, |n, &limb| (n << 32) | limb as u128),
+        )
+    }
+
+    /// Multiplies in place by a single limb and adds another.
+    fn mul_add_small(&mut self, factor: u32, addend: u32) {
+        let mut carry = addend as u64;
+        for limb in self.limbs.iter_mut() {
+            let cur = *limb as u64 * factor as u64 + carry;
+            *limb = cur as u32;
+            carry = cur >> 32;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+        self.normalize();
+    }
+
+    /// Divides in place by a single limb and returns the remainder.
+    fn div_small_assign(&mut self, divisor: u32) -> u32 {
+        let mut rem: u64 = 0;
+        for limb in self.limbs.iter_mut().rev() {
+            let cur = (rem << 32) | *limb as u64;
+            *limb = (cur / divisor as u64) as u32;
+            rem = cur % divisor as u64;
+        }
+        self.normalize();
+        rem as u32
+    }
+
+    /// Quotient and remainder by a single limb. Panics if `divisor` is 0.
+    pub fn div_rem_small(&self, divisor: u32) -> (BigUint, u32) {
+        assert!(divisor != 0, "division by zero");
+        let mut quotient = self.clone();
+        let rem = quotient.div_small_assign(divisor);
+        (quotient, rem)
+    }
+
+    /// Quotient and remainder. Panics if `divisor` is 0.
+    pub fn div_rem(&self, divisor: &BigUint) -> (BigUint, BigUint) {
+        assert!(!divisor.is_zero(), "division by zero");
+        if self < divisor {
+            return (BigUint::zero(), self.clone());
+        }
+        if let [small] = divisor.limbs[..] {
+            let (quotient, rem) = self.div_rem_small(small);
+            return (quotient, BigUint::from(rem as u64));
+        }
+        let (quotient, rem) = div_rem_limbs(&self.limbs, &divisor.limbs);
+        (BigUint::from_limbs(quotient), BigUint::from_limbs(rem))
+    }
+
+    pub fn checked_sub(&self, other: &BigUint) -> Option<BigUint> {
+        if self < other {
+            return None;
+        }
+        let mut limbs = self.limbs.clone();
+        sub_assign_limbs(&mut limbs, &other.limbs);
+        Some(BigUint::from_limbs(limbs))
+    }
+
+    pub fn pow(&self, mut exp: u32) -> BigUint {
+        let mut base = self.clone();
+        let mut result = BigUint::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+}
+
+/// `a + b`, both little-endian limbs.
+fn add_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut limbs = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+    for (i, &limb) in long.iter().enumerate() {
+        let sum = limb as u64 + *short.get(i).unwrap_or(&0) as u64 + carry;
+        limbs.push(sum as u32);
+        carry = sum >> 32;
+    }
+    if carry > 0 {
+        limbs.push(carry as u32);
+    }
+    limbs
+}
+
+/// `a -= b`, for `a >= b`.
+fn sub_assign_limbs(a: &mut [u32], b: &[u32]) {
+    let mut borrow = 0i64;
+    for (i, limb) in a.iter_mut().enumerate() {
+        if i >= b.len() && borrow == 0 {
+            break;
+        }
+        let diff = *limb as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        *limb = diff as u32;
+        borrow = (diff < 0) as i64;
+    }
+    debug_assert_eq!(borrow, 0, "subtraction underflowed");
+}
+
+/// `acc += b << (32 * shift)`, growing `acc` as needed.
+fn add_shifted(acc: &mut Vec<u32>, b: &[u32], shift: usize) {
+    if acc.len() < shift + b.len() + 1 {
+        acc.resize(shift + b.len() + 1, 0);
+    }
+    let mut carry = 0u64;
+    for (i, slot) in acc[shift..].iter_mut().enumerate() {
+        if i >= b.len() && carry == 0 {
+            break;
+        }
+        let sum = *slot as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        *slot = sum as u32;
+        carry = sum >> 32;
+    }
+}
+
+fn trim(mut limbs: &[u32]) -> &[u32] {
+    while let [rest @ .., 0] = limbs {
+        limbs = rest;
+    }
+    limbs
+}
+
+fn mul_schoolbook(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut product = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let cur = product[i + j] as u64 + x as u64 * y as u64 + carry;
+            product[i + j] = cur as u32;
+            carry = cur >> 32;
+        }
+        product[i + b.len()] = carry as u32;
+    }
+    product
+}
+
+/// `a * b`, splitting both in half while they're long enough:
+/// `(a1 B + a0)(b1 B + b0)` takes three half-size products instead of four.
+fn mul_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (a, b) = (trim(a), trim(b));
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        return mul_schoolbook(a, b);
+    }
+    let half = a.len().max(b.len()) / 2;
+    let (a0, a1) = a.split_at(half.min(a.len()));
+    let (b0, b1) = b.split_at(half.min(b.len()));
+
+    let z0 = mul_limbs(a0, b0);
+    let z2 = mul_limbs(a1, b1);
+    let mut z1 = mul_limbs(&add_limbs(a0, a1), &add_limbs(b0, b1));
+    sub_assign_limbs(&mut z1, &z0);
+    sub_assign_limbs(&mut z1, &z2);
+
+    let mut product = z0;
+    add_shifted(&mut product, trim(&z1), half);
+    add_shifted(&mut product, trim(&z2), 2 * half);
+    product
+}
+
+fn shl_bits(limbs: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return limbs.to_vec();
+    }
+    let mut out = Vec::with_capacity(limbs.len() + 1);
+    let mut carry = 0;
+    for &limb in limbs {
+        out.push((limb << shift) | carry);
+        carry = limb >> (32 - shift);
+    }
+    out.push(carry);
+    out
+}
+
+fn shr_bits(limbs: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return limbs.to_vec();
+    }
+    (0..limbs.len())
+        .map(|i| (limbs[i] >> shift) | limbs.get(i + 1).map_or(0, |&next| next << (32 - shift)))
+        .collect()
+}
+
+/// Long division of `u` by a `v` of at least two limbs, by Knuth's
+/// algorithm D: each quotient limb is estimated from the top limbs, then
+/// corrected at most twice.
+fn div_rem_limbs(u: &[u32], v: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    // Shift both so the divisor's top bit is set, which keeps the estimates
+    // close.
+    let shift = v.last().unwrap().leading_zeros();
+    let v = shl_bits(v, shift);
+    let v = trim(&v);
+    // One spare limb on top of the dividend, which the shift may fill.
+    let mut u = shl_bits(u, shift);
+    if shift == 0 {
+        u.push(0);
+    }
+    let n = v.len();
+    let m = u.len() - n - 1;
+    let (top, second) = (v[n - 1] as u64, v[n - 2] as u64);
+    let mut quotient = vec![0u32; m + 1];
+
+    for j in (0..=m).rev() {
+        let numerator = ((u[j + n] as u64) << 32) | u[j + n - 1] as u64;
+        let mut qhat = numerator / top;
+        let mut rhat = numerator % top;
+        while qhat >> 32 != 0 || qhat * second > ((rhat << 32) | u[j + n - 2] as u64) {
+            qhat -= 1;
+            rhat += top;
+            if rhat >> 32 != 0 {
+                break;
+            }
+        }
+
+        // u[j..=j + n] -= qhat * v
+        let mut borrow = 0i64;
+        let mut carry = 0u64;
+        for i in 0..n {
+            let product = qhat * v[i] as u64 + carry;
+            carry = product >> 32;
+            let diff = u[i + j] as i64 - borrow - (product & 0xffff_ffff) as i64;
+            u[i + j] = diff as u32;
+            borrow = (diff < 0) as i64;
+        }
+        let diff = u[j + n] as i64 - borrow - carry as i64;
+        u[j + n] = diff as u32;
+
+        if diff < 0 {
+            // The estimate was one too big: add one `v` back.
+            qhat -= 1;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let sum = u[i + j] as u64 + v[i] as u64 + carry;
+                u[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as u32);
+        }
+        quotient[j] = qhat as u32;
+    }
+
+    (quotient, shr_bits(&u[..n], shift))
+}
+
+impl From<u64> for BigUint {
+    fn from(n: u64) -> Self {
+        BigUint::from_limbs(vec![n as u32, (n >> 32) as u32])
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(n: u128) -> Self {
+        BigUint::from_limbs((0..4).map(|i| (n >> (32 * i)) as u32).collect())
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigIntError;
+
+impl fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not a decimal number")
+    }
+}
+
+impl std::error::Error for ParseBigIntError {}
+
+impl FromStr for BigUint {
+    type Err = ParseBigIntError;
+
+    /// Parses decimal digits, nine at a time.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseBigIntError);
+        }
+        let mut big = BigUint::zero();
+        let mut rest = s;
+        let mut chunk_len = match s.len() % 9 {
+            0 => 9,
+            n => n,
+        };
+        while !rest.is_empty() {
+            let (chunk, tail) = rest.split_at(chunk_len);
+            big.mul_add_small(10u32.pow(chunk_len as u32), chunk.parse().unwrap());
+            rest = tail;
+            chunk_len = 9;
+        }
+        Ok(big)
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(true, "", &decimal(self))
+    }
+}
+
+/// Decimal digits of `n`, peeled off nine at a time, least significant
+/// first.
+fn decimal(n: &BigUint) -> String {
+    if n.is_zero() {
+        return "0".to_string();
+    }
+    let mut rest = n.clone();
+    let mut chunks = Vec::new();
+    while !rest.is_zero() {
+        chunks.push(rest.div_small_assign(1_000_000_000));
+    }
+
+    let mut digits = chunks.pop().unwrap().to_string();
+    for chunk in chunks.iter().rev() {
+        digits.push_str(&format!("{:09}", chunk));
+    }
+    digits
+}
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        BigUint::from_limbs(add_limbs(&self.limbs, &other.limbs))
+    }
+}
+
+impl Sub<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    /// Panics if `other` is bigger; see [`BigUint::checked_sub`].
+    fn sub(self, other: &BigUint) -> BigUint {
+        self.checked_sub(other)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        BigUint::from_limbs(mul_limbs(&self.limbs, &other.limbs))
+    }
+}
+
+impl Div<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn div(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn rem(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).1
+    }
+}
+
+impl Sum for BigUint {
+    fn sum<I: Iterator<Item = BigUint>>(iter: I) -> Self {
+        iter.fold(BigUint::zero(), |acc, n| acc + n)
+    }
+}
+
+impl<'a> Sum<&'a BigUint> for BigUint {
+    fn sum<I: Iterator<Item = &'a BigUint>>(iter: I) -> Self {
+        iter.fold(BigUint::zero(), |acc, n| acc + n)
+    }
+}
+
+impl Product for BigUint {
+    fn product<I: Iterator<Item = BigUint>>(iter: I) -> Self {
+        iter.fold(BigUint::one(), |acc, n| acc * n)
+    }
+}
+
+impl<'a> Product<&'a BigUint> for BigUint {
+    fn product<I: Iterator<Item = &'a BigUint>>(iter: I) -> Self {
+        iter.fold(BigUint::one(), |acc, n| acc * n)
+    }
+}
+
+/// Arbitrary-precision signed integer: a sign and a [`BigUint`] magnitude.
+/// Zero is never negative. Division truncates toward zero, like the
+/// primitive types.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: BigUint,
+}
+
+impl BigInt {
+    pub fn zero() -> Self {
+        BigInt::default()
+    }
+
+    pub fn one() -> Self {
+        BigInt::from(BigUint::one())
+    }
+
+    fn new(negative: bool, magnitude: BigUint) -> Self {
+        BigInt {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    /// The value as an `i128`, if it fits.
+    pub fn to_i128(&self) -> Option<i128> {
+        let magnitude = self.magnitude.to_u128()?;
+        match self.negative {
+            true => 0i128.checked_sub_unsigned(magnitude),
+            false => i128::try_from(magnitude).ok(),
+        }
+    }
+
+    /// Quotient rounded toward zero and remainder with the sign of `self`.
+    /// Panics if `divisor` is 0.
+    pub fn div_rem(&self, divisor: &BigInt) -> (BigInt, BigInt) {
+        let (quotient, rem) = self.magnitude.div_rem(&divisor.magnitude);
+        (
+            BigInt::new(self.negative != divisor.negative, quotient),
+            BigInt::new(self.negative, rem),
+        )
+    }
+
+    pub fn pow(&self, exp: u32) -> BigInt {
+        BigInt::new(self.negative && exp % 2 == 1, self.magnitude.pow(exp))
+    }
+}
+
+impl From<BigUint> for BigInt {
+    fn from(magnitude: BigUint) -> Self {
+        BigInt::new(false, magnitude)
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(n: i64) -> Self {
+        BigInt::new(n < 0, BigUint::from(n.unsigned_abs()))
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(n: i128) -> Self {
+        BigInt::new(n < 0, BigUint::from(n.unsigned_abs()))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    /// Parses decimal digits with an optional leading `+` or `-`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix('-') {
+            Some(digits) => Ok(BigInt::new(true, digits.parse()?)),
+            None => Ok(BigInt::from(
+                s.strip_prefix('+').unwrap_or(s).parse::<BigUint>()?,
+            )),
+        }
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad_integral(!self.negative, "", &decimal(&self.magnitude))
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.magnitude)
+    }
+}
+
+impl Add<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(self.negative, &self.magnitude + &other.magnitude);
+        }
+        // Opposite signs: the bigger magnitude wins.
+        match self.magnitude.cmp(&other.magnitude) {
+            Ordering::Less => BigInt::new(other.negative, &other.magnitude - &self.magnitude),
+            _ => BigInt::new(self.negative, &self.magnitude - &other.magnitude),
+        }
+    }
+}
+
+impl Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        self + &-other
+    }
+}
+
+impl Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        BigInt::new(
+            self.negative != other.negative,
+            &self.magnitude * &other.magnitude,
+        )
+    }
+}
+
+impl Div<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).1
+    }
+}
+
+impl Sum for BigInt {
+    fn sum<I: Iterator<Item = BigInt>>(iter: I) -> Self {
+        iter.fold(BigInt::zero(), |acc, n| acc + n)
+    }
+}
+
+impl<'a> Sum<&'a BigInt> for BigInt {
+    fn sum<I: Iterator<Item = &'a BigInt>>(iter: I) -> Self {
+        iter.fold(BigInt::zero(), |acc, n| acc + n)
+    }
+}
+
+impl Product for BigInt {
+    fn product<I: Iterator<Item = BigInt>>(iter: I) -> Self {
+        iter.fold(BigInt::one(), |acc, n| acc * n)
+    }
+}
+
+impl<'a> Product<&'a BigInt> for BigInt {
+    fn product<I: Iterator<Item = &'a BigInt>>(iter: I) -> Self {
+        iter.fold(BigInt::one(), |acc, n| acc * n)
+    }
+}
+
+/// The owned and mixed forms of a binary operator and its assigning form,
+/// all in terms of the `&T op &T` impl.
+macro_rules! forward_ops {
+    ($t:ty: $($op:ident $method:ident $assign:ident $assign_method:ident),*) => {
+        $(
+            impl $op for $t {
+                type Output = $t;
+
+                fn $method(self, other: $t) -> $t {
+                    (&self).$method(&other)
+                }
+            }
+
+            impl $op<&$t> for $t {
+                type Output = $t;
+
+                fn $method(self, other: &$t) -> $t {
+                    (&self).$method(other)
+                }
+            }
+
+            impl $op<$t> for &$t {
+                type Output = $t;
+
+                fn $method(self, other: $t) -> $t {
+                    self.$method(&other)
+                }
+            }
+
+            impl $assign<&$t> for $t {
+                fn $assign_method(&mut self, other: &$t) {
+                    *self = (&*self).$method(other);
+                }
+            }
+
+            impl $assign for $t {
+                fn $assign_method(&mut self, other: $t) {
+                    *self = (&*self).$method(&other);
+                }
+            }
+        )*
+    };
+}
+
+forward_ops!(BigUint: Add add AddAssign add_assign, Sub sub SubAssign sub_assign, Mul mul MulAssign mul_assign);
+forward_ops!(BigInt: Add add AddAssign add_assign, Sub sub SubAssign sub_assign, Mul mul MulAssign mul_assign);
+
+macro_rules! forward_div {
+    ($t:ty: $($op:ident $method:ident),*) => {
+        $(
+            impl $op for $t {
+                type Output = $t;
+
+                fn $method(self, other: $t) -> $t {
+                    (&self).$method(&other)
+                }
+            }
+
+            impl $op<&$t> for $t {
+                type Output = $t;
+
+                fn $method(self, other: &$t) -> $t {
+                    (&self).$method(other)
+                }
+            }
+        )*
+    };
+}
+
+forward_div!(BigUint: Div div, Rem rem);
+forward_div!(BigInt: Div div, Rem rem);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    fn random(rng: &mut Rng, limbs: usize) -> BigUint {
+        BigUint::from_limbs((0..limbs).map(|_| rng.next_u64() as u32).collect())
+    }
+
+    #[test]
+    fn test_add_carries() {
+        let a = BigUint::from(u64::MAX);
+        let sum = &a + &a;
+        assert_eq!(sum.to_string(), (u64::MAX as u128 * 2).to_string());
+        assert_eq!((sum + BigUint::zero()).limbs.len(), 3);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(BigUint::zero().to_string(), "0");
+        assert_eq!(BigUint::from(1_000_000_000u64).to_string(), "1000000000");
+        assert_eq!(format!("{:>5}", BigUint::from(42u64)), "   42");
+        assert_eq!(format!("{:>5}", BigInt::from(-42i64)), "  -42");
+        assert_eq!(BigInt::from(i128::MIN).to_string(), i128::MIN.to_string());
+    }
+
+    #[test]
+    fn test_parse() {
+        let digits = "340282366920938463463374607431768211456123";
+        assert_eq!(digits.parse::<BigUint>().unwrap().to_string(), digits);
+        assert_eq!("000".parse::<BigUint>(), Ok(BigUint::zero()));
+        assert_eq!(
+            u128::MAX.to_string().parse::<BigUint>(),
+            Ok(BigUint::from(u128::MAX))
+        );
+        assert_eq!("".parse::<BigUint>(), Err(ParseBigIntError));
+        assert_eq!("-1".parse::<BigUint>(), Err(ParseBigIntError));
+        assert_eq!(
+            format!("-{}", digits)
+                .parse::<BigInt>()
+                .unwrap()
+                .to_string(),
+            format!("-{}", digits)
+        );
+        assert_eq!("-0".parse::<BigInt>(), Ok(BigInt::zero()));
+        assert_eq!("+7".parse::<BigInt>(), Ok(BigInt::from(7i64)));
+        assert_eq!("--7".parse::<BigInt>(), Err(ParseBigIntError));
+    }
+
+    #[test]
+    fn test_matches_u128() {
+        let mut rng = Rng::new(50);
+        for _ in 0..1000 {
+            let a = rng.next_u64() as u128 * rng.below(1 << 20) as u128;
+            let b = 1 + rng.next_u64() as u128 % (1 << rng.below(64));
+            let (big_a, big_b) = (BigUint::from(a), BigUint::from(b));
+            assert_eq!((&big_a + &big_b).to_u128(), a.checked_add(b));
+            assert_eq!(
+                big_a.checked_sub(&big_b).map(|n| n.to_u128().unwrap()),
+                a.checked_sub(b)
+            );
+            assert_eq!((&big_a * &big_b).to_u128(), a.checked_mul(b));
+            let (q, r) = big_a.div_rem(&big_b);
+            assert_eq!((q.to_u128(), r.to_u128()), (Some(a / b), Some(a % b)));
+        }
+    }
+
+    #[test]
+    fn test_karatsuba_matches_schoolbook() {
+        let mut rng = Rng::new(7);
+        for (x, y) in [(32, 32), (40, 100), (150, 33), (257, 255)] {
+            let (a, b) = (random(&mut rng, x), random(&mut rng, y));
+            let expected = BigUint::from_limbs(mul_schoolbook(&a.limbs, &b.limbs));
+            assert_eq!(&a * &b, expected);
+        }
+        let all_ones = BigUint::from_limbs(vec![u32::MAX; 64]);
+        assert_eq!(
+            &all_ones * &all_ones,
+            BigUint::from_limbs(mul_schoolbook(&all_ones.limbs, &all_ones.limbs))
+        );
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let mut rng = Rng::new(11);
+        for _ in 0..200 {
+            let (a_len, b_len) = (1 + rng.below(40), 1 + rng.below(20));
+            let a = random(&mut rng, a_len as usize);
+            let b = random(&mut rng, b_len as usize);
+            if b.is_zero() {
+                continue;
+            }
+            let (q, r) = a.div_rem(&b);
+            assert!(r < b);
+            assert_eq!(&q * &b + &r, a);
+        }
+        // Quotient limbs whose first estimate is too big.
+        let a = BigUint::from_limbs(vec![0, 0, 0x8000_0000, 0x7fff_ffff]);
+        let b = BigUint::from_limbs(vec![1, 0x8000_0000]);
+        let (q, r) = a.div_rem(&b);
+        assert_eq!(&q * &b + &r, a);
+
+        let (q, r) = BigUint::from(1001u64).div_rem_small(10);
+        assert_eq!((q, r), (BigUint::from(100u64), 1));
+    }
+
+    #[test]
+    fn test_signed() {
+        for (a, b) in [(7i64, 2i64), (-7, 2), (7, -2), (-7, -2), (0, -3), (-3, 5)] {
+            let (x, y) = (BigInt::from(a), BigInt::from(b));
+            assert_eq!(&x + &y, BigInt::from(a + b));
+            assert_eq!(&x - &y, BigInt::from(a - b));
+            assert_eq!(&x * &y, BigInt::from(a * b));
+            assert_eq!(&x / &y, BigInt::from(a / b));
+            assert_eq!(&x % &y, BigInt::from(a % b));
+            assert_eq!(x.cmp(&y), a.cmp(&b));
+        }
+        assert_eq!(
+            BigInt::from(-2i64).pow(63).to_i128(),
+            Some(i64::MIN as i128)
+        );
+        assert_eq!(BigInt::from(i128::MIN).to_i128(), Some(i128::MIN));
+        assert_eq!((-BigInt::from(i128::MIN)).to_i128(), None);
+    }
+
+    #[test]
+    fn test_sum_and_product() {
+        let factorial: BigUint = (1..=30u64).map(BigUint::from).product();
+        assert_eq!(factorial.to_string(), "265252859812191058636308480000000");
+        let total: BigUint = [&factorial, &factorial].into_iter().sum();
+        assert_eq!(total, &factorial * &BigUint::from(2u64));
+        let signed: BigInt = [-5i64, 3, -1].into_iter().map(BigInt::from).sum();
+        assert_eq!(signed, BigInt::from(-3i64));
+        assert_eq!(
+            std::iter::empty::<BigInt>().product::<BigInt>(),
+            BigInt::one()
+        );
+    }
+}
@@ -0,0 +1,714 @@
+use advent_of_code::explain::Trace;
+use advent_of_code::fixtures;
+use advent_of_code::registry::{self, Answer, DEFAULT_YEAR, Params};
+use advent_of_code::repl;
+use advent_of_code::report::{self, Format, Record};
+use advent_of_code::rng::Rng;
+use advent_of_code::utils::{Fnv1a, fnv1a};
+use advent_of_code::viz::{self, Pace, Player};
+use advent_of_code::watch::Watcher;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+fn usage() -> ! {
+    eprintln!("Usage: cargo run --bin aoc -- <command> [options]");
+    eprintln!();
+    eprintln!(
+        "Every command takes --year Y to pick the event (default {}).",
+        DEFAULT_YEAR
+    );
+    eprintln!();
+    eprintln!("Commands:");
+    eprintln!("  run <day> [--input FILE] [--param KEY=VALUE]... [--format json|csv|table]");
+    eprintln!("      Solve both parts of a day (default input inputs/<year>/dayNN.txt).");
+    eprintln!("      With --stream, days 1, 2, 3 and 5 read the input without loading it.");
+    eprintln!("  check [<day>] [--format json|csv|table]");
+    eprintln!("      Check the examples in inputs/<year>/fixtures.toml, for one day or all.");
+    eprintln!("  bench <day> [--runs N] [--input FILE] [--param KEY=VALUE]... [--format ...]");
+    eprintln!("      Time both parts of a day over N runs (default 10).");
+    eprintln!("  run <day> --explain [--limit N]");
+    eprintln!("      Print the first N steps behind each answer to stderr (default 20),");
+    eprintln!("      for days 1, 2, 3, 6 and 8.");
+    eprintln!("  run <day> --online");
+    eprintln!("      Feed days 1, 3, 5 and 7 from stdin a line at a time, printing the");
+    eprintln!("      answers so far after each line. A line reading :reset starts over.");
+    eprintln!("  run <day> --viz [--fps N | --step] [--no-highlight] [--dump FILE]");
+    eprintln!("      Visualize days 4 and 7 in the terminal, or dump the frames to FILE.");
+    eprintln!("  repl <day> [--input FILE]");
+    eprintln!("      Load a day's input and explore it with commands (days 4, 5 and 8;");
+    eprintln!("      type help for the list).");
+    eprintln!("  watch <day> [--interval MS]");
+    eprintln!("      Rebuild and check a day's examples, then its input, on every change");
+    eprintln!("      under src/ or inputs/ (polls every 500ms by default).");
+    eprintln!("  image <day> [--input FILE] [--out FILE] [--param KEY=VALUE]...");
+    eprintln!("      Draw day 4 as a GIF or days 7 and 8 as a PNG (default dayNN.gif/png).");
+    eprintln!("  svg <day> [--input FILE] [--plane xy|xz|yz|iso] [--angle DEG] [--connections K]");
+    eprintln!("        [--tree] [--size N] [--param KEY=VALUE]... [--out FILE]");
+    eprintln!("      Draw 2025 day 8's circuits as SVG (default 1000 connections, dayNN.svg).");
+    eprintln!("  gen <day> [--size N] [--seed S] [--out FILE]");
+    eprintln!("      Generate a random input for a day (2025 days 1 to 8; default size 1000,");
+    eprintln!("      seed 0).");
+    eprintln!("      Writes to stdout unless --out is given.");
+    process::exit(1);
+}
+
+/// The day named by `arg`, if it was part of `year`'s event.
+fn parse_day(year: u32, arg: Option<&String>) -> u32 {
+    let Some(Ok(day)) = arg.map(|a| a.parse()) else {
+        eprintln!("Expected a day number");
+        process::exit(1);
+    };
+    if let Err(e) = registry::validate(year, day) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+    day
+}
+
+fn option<'a>(args: &'a [String], name: &str) -> Option<&'a str> {
+    let pos = args.iter().position(|arg| arg == name)?;
+    match args.get(pos + 1) {
+        Some(value) => Some(value),
+        None => {
+            eprintln!("Missing value for {}", name);
+            process::exit(1);
+        }
+    }
+}
+
+fn numeric_option<T: std::str::FromStr>(args: &[String], name: &str, default: T) -> T {
+    match option(args, name).map(str::parse) {
+        None => default,
+        Some(Ok(value)) => value,
+        Some(Err(_)) => {
+            eprintln!("{} expects a number", name);
+            process::exit(1);
+        }
+    }
+}
+
+fn input_path(year: u32, day: u32, args: &[String]) -> PathBuf {
+    match option(args, "--input") {
+        Some(path) => path.into(),
+        None => registry::input_path(year, day),
+    }
+}
+
+/// The input for `year`'s `day`, and the path it was read from.
+fn read_input(year: u32, day: u32, args: &[String]) -> (String, String) {
+    let path = input_path(year, day, args);
+    match fs::read_to_string(&path) {
+        Ok(input) => (path.display().to_string(), input),
+        Err(e) => {
+            eprintln!("Failed to read {}: {}", path.display(), e);
+            process::exit(1);
+        }
+    }
+}
+
+fn year(args: &[String]) -> u32 {
+    numeric_option(args, "--year", DEFAULT_YEAR)
+}
+
+fn format(args: &[String]) -> Format {
+    match option(args, "--format").map(str::parse) {
+        None => Format::Table,
+        Some(Ok(format)) => format,
+        Some(Err(e)) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Solves one part `runs` times, returning the last answer and every timing.
+fn measure(
+    year: u32,
+    day: u32,
+    part: u32,
+    input: &str,
+    params: &Params,
+    runs: usize,
+) -> Option<(Answer, Vec<Duration>)> {
+    let mut timings = Vec::with_capacity(runs);
+    let mut answer = None;
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        answer = Some(registry::solve(year, day, part, input, params)?);
+        timings.push(start.elapsed());
+    }
+    Some((answer?, timings))
+}
+
+fn open(path: &Path) -> BufReader<fs::File> {
+    match fs::File::open(path) {
+        Ok(file) => BufReader::new(file),
+        Err(e) => {
+            eprintln!("Failed to read {}: {}", path.display(), e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Hashes a file without loading it.
+fn hash_file(path: &Path) -> u64 {
+    let mut reader = open(path);
+    let mut hash = Fnv1a::new();
+    loop {
+        let chunk = match reader.fill_buf() {
+            Ok([]) => return hash.finish(),
+            Ok(chunk) => chunk,
+            Err(e) => {
+                eprintln!("Failed to read {}: {}", path.display(), e);
+                process::exit(1);
+            }
+        };
+        hash.update(chunk);
+        let len = chunk.len();
+        reader.consume(len);
+    }
+}
+
+/// Like [`solve_parts`], but reads the input file afresh on every run
+/// instead of loading it into memory.
+fn stream_parts(year: u32, day: u32, args: &[String], runs: usize) -> Vec<Record> {
+    let path = input_path(year, day, args);
+    let input_hash = hash_file(&path);
+    let params = params(args);
+    (1..=2)
+        .map(|part| {
+            let mut timings = Vec::with_capacity(runs);
+            let mut answer = None;
+            for _ in 0..runs.max(1) {
+                let mut reader = open(&path);
+                let start = Instant::now();
+                match registry::solve_stream(year, day, part, &mut reader, &params) {
+                    Some(Ok(a)) => answer = Some(a),
+                    Some(Err(e)) => {
+                        eprintln!("Failed to read {}: {}", path.display(), e);
+                        process::exit(1);
+                    }
+                    None => {
+                        eprintln!("{} day {} can't stream its input", year, day);
+                        process::exit(1);
+                    }
+                }
+                timings.push(start.elapsed());
+            }
+            let answer = answer.unwrap();
+            Record {
+                day,
+                part,
+                answer,
+                expected: None,
+                input: path.display().to_string(),
+                input_hash,
+                timings,
+            }
+        })
+        .collect()
+}
+
+/// Runs both parts of `year`'s `day` and records them, exiting if the day
+/// has no solver.
+fn solve_parts(year: u32, day: u32, args: &[String], runs: usize) -> Vec<Record> {
+    if args.iter().any(|arg| arg == "--stream") {
+        return stream_parts(year, day, args, runs);
+    }
+    let (path, input) = read_input(year, day, args);
+    let params = params(args);
+    (1..=2)
+        .map(|part| {
+            let Some((answer, timings)) = measure(year, day, part, &input, &params, runs) else {
+                eprintln!("{} day {} is not solved yet", year, day);
+                process::exit(1);
+            };
+            Record {
+                day,
+                part,
+                answer,
+                expected: None,
+                input: path.clone(),
+                input_hash: fnv1a(input.as_bytes()),
+                timings,
+            }
+        })
+        .collect()
+}
+
+fn print_records(records: &[Record], args: &[String]) {
+    if let Err(e) = report::write(records, format(args), &mut io::stdout()) {
+        eprintln!("Failed to write results: {}", e);
+        process::exit(1);
+    }
+}
+
+/// Every `--param KEY=VALUE` in `args`.
+fn params(args: &[String]) -> Params {
+    let mut params = Params::new();
+    for (i, arg) in args.iter().enumerate() {
+        if arg != "--param" {
+            continue;
+        }
+        match args.get(i + 1).and_then(|p| p.split_once('=')) {
+            Some((key, value)) => params.insert(key.to_string(), value.to_string()),
+            None => {
+                eprintln!("--param expects KEY=VALUE");
+                process::exit(1);
+            }
+        };
+    }
+    params
+}
+
+fn visualize(year: u32, day: u32, input: &str, args: &[String]) {
+    let Some((frames, palette)) = registry::visualize(year, day, input) else {
+        eprintln!("No visualization for {} day {}", year, day);
+        process::exit(1);
+    };
+
+    let result = match option(args, "--dump") {
+        Some(path) => fs::File::create(path)
+            .and_then(|mut file| viz::dump(&frames, &mut file))
+            .map(|_| eprintln!("Wrote {} frames to {}", frames.len(), path)),
+        None => {
+            let pace = if args.iter().any(|arg| arg == "--step") {
+                Pace::Step
+            } else {
+                let fps: f64 = numeric_option(args, "--fps", 10.0);
+                if !(fps > 0.0 && fps.is_finite()) {
+                    eprintln!("--fps expects a positive number, not {}", fps);
+                    process::exit(1);
+                }
+                Pace::Fps(fps)
+            };
+            Player::new(palette)
+                .pace(pace)
+                .highlight(!args.iter().any(|arg| arg == "--no-highlight"))
+                .play(&frames, &mut io::stdout(), &mut io::stdin().lock())
+        }
+    };
+    if let Err(e) = result {
+        eprintln!("Visualization failed: {}", e);
+        process::exit(1);
+    }
+}
+
+/// Drives an online solver from stdin, printing both running answers after
+/// every accepted line.
+fn online(year: u32, day: u32) {
+    let Some(mut solver) = registry::online(year, day) else {
+        eprintln!("No online solver for {} day {}", year, day);
+        process::exit(1);
+    };
+    eprintln!(
+        "Reading {} day {} from stdin (:reset starts over, Ctrl-D stops)",
+        year, day
+    );
+    for line in io::stdin().lock().lines() {
+        let line = line.unwrap_or_else(|e| {
+            eprintln!("Failed to read stdin: {}", e);
+            process::exit(1);
+        });
+        if line == ":reset" {
+            solver.reset();
+            println!("reset");
+            continue;
+        }
+        if let Err(e) = solver.push_line(&line) {
+            eprintln!("rejected: {}", e);
+            continue;
+        }
+        let answers: Vec<String> = (1..=2)
+            .map(|part| {
+                let answer = solver.current_answer(part);
+                format!(
+                    "part {} {}",
+                    part,
+                    answer.map_or("-".to_string(), |a| a.to_string())
+                )
+            })
+            .collect();
+        println!("{}", answers.join(" | "));
+    }
+}
+
+/// Prints the steps each part notes on its way to the answer, to stderr so
+/// the records still go to stdout on their own.
+fn explain(year: u32, day: u32, args: &[String]) {
+    let (_, input) = read_input(year, day, args);
+    let params = params(args);
+    let limit = numeric_option(args, "--limit", 20);
+    for part in 1..=2 {
+        let mut trace = Trace::new(limit);
+        let Some(answer) = registry::explain(year, day, part, &input, &params, &mut trace) else {
+            eprintln!("No explanation for {} day {}", year, day);
+            process::exit(1);
+        };
+        eprintln!("part {}:", part);
+        for line in trace.lines() {
+            eprintln!("  {}", line);
+        }
+        if trace.dropped() > 0 {
+            eprintln!("  ... {} more steps (raise --limit)", trace.dropped());
+        }
+        eprintln!("  answer {}", answer);
+    }
+}
+
+fn run(args: &[String]) {
+    let year = year(args);
+    let day = parse_day(year, args.first());
+    if args.iter().any(|arg| arg == "--online") {
+        online(year, day);
+        return;
+    }
+    if args.iter().any(|arg| arg == "--explain") {
+        explain(year, day, args);
+    }
+    if args.iter().any(|arg| arg == "--viz") {
+        let (_, input) = read_input(year, day, args);
+        visualize(year, day, &input, args);
+        return;
+    }
+    print_records(&solve_parts(year, day, args, 1), args);
+}
+
+fn check(args: &[String]) {
+    let year = year(args);
+    let day = args
+        .first()
+        .filter(|arg| !arg.starts_with("--"))
+        .map(|_| parse_day(year, args.first()));
+    let fixtures = fixtures::load(year).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+
+    let dir = registry::year_dir(year);
+    let mut records = Vec::new();
+    for fixture in fixtures.iter().filter(|f| day.is_none_or(|d| f.day == d)) {
+        let input = fixture.input(&dir).unwrap_or_else(|e| {
+            eprintln!("Failed to read {}: {}", fixture.name(), e);
+            process::exit(1);
+        });
+        for (&part, expected) in &fixture.expected {
+            let Some((answer, timings)) =
+                measure(year, fixture.day, part, &input, &fixture.params, 1)
+            else {
+                eprintln!(
+                    "{} day {} part {} is not solved yet",
+                    year, fixture.day, part
+                );
+                process::exit(1);
+            };
+            records.push(Record {
+                day: fixture.day,
+                part,
+                answer,
+                expected: Some(expected.clone()),
+                input: fixture.name(),
+                input_hash: fnv1a(input.as_bytes()),
+                timings,
+            });
+        }
+    }
+    let failed = records.iter().any(|r| r.passed() == Some(false));
+    print_records(&records, args);
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn explore(args: &[String]) {
+    let year = year(args);
+    let day = parse_day(year, args.first());
+    let (path, input) = read_input(year, day, args);
+    let Some(mut session) = registry::repl(year, day, &input) else {
+        eprintln!("No REPL for {} day {}", year, day);
+        process::exit(1);
+    };
+    eprintln!("Loaded {}; type help for commands", path);
+
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        print!("day {}> ", day);
+        let _ = io::stdout().flush();
+        let Some(line) = lines.next() else {
+            println!();
+            return;
+        };
+        let line = line.unwrap_or_else(|e| {
+            eprintln!("Failed to read stdin: {}", e);
+            process::exit(1);
+        });
+        match line.trim() {
+            "quit" | "exit" => return,
+            line => match repl::execute(session.as_mut(), line) {
+                Ok(output) if output.is_empty() => {}
+                Ok(output) => println!("{}", output),
+                Err(e) => println!("error: {}", e),
+            },
+        }
+    }
+}
+
+fn bench(args: &[String]) {
+    let year = year(args);
+    let day = parse_day(year, args.first());
+    let runs = numeric_option(args, "--runs", 10);
+    print_records(&solve_parts(year, day, args, runs), args);
+}
+
+/// Current time of day, UTC, as `hh:mm:ss`.
+fn clock() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+        % 86400;
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// Runs the freshly built runner with `args` and reads its CSV records. On
+/// failure, returns whatever it printed.
+fn run_child(exe: &Path, args: &[&str]) -> Result<Vec<BTreeMap<String, String>>, String> {
+    let output = Command::new(exe)
+        .args(args)
+        .args(["--format", "csv"])
+        .output()
+        .map_err(|e| format!("failed to start {}: {}", exe.display(), e))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if output.status.success() {
+        return Ok(report::read_csv(&stdout));
+    }
+    Err(format!(
+        "{}{}",
+        stdout,
+        String::from_utf8_lossy(&output.stderr)
+    ))
+}
+
+fn nanos(record: &BTreeMap<String, String>) -> Duration {
+    Duration::from_nanos(record["mean_ns"].parse().unwrap_or(0))
+}
+
+/// One rebuild, check and run of `year`'s `day`, summed up in one line,
+/// followed by details if something failed. `previous` holds the last
+/// answers by part.
+fn watch_once(year: u32, day: u32, root: &Path, previous: &mut BTreeMap<String, String>) -> String {
+    let mut line = format!("[{}] {} day {}", clock(), year, day);
+
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let start = Instant::now();
+    let build = Command::new(cargo)
+        .args(["build", "-q", "--bin", "aoc"])
+        .current_dir(root)
+        .output();
+    match build {
+        Ok(output) if output.status.success() => {
+            line += &format!(" | build {}", report::Human(start.elapsed()));
+        }
+        Ok(output) => {
+            return format!(
+                "{} | build FAILED\n{}",
+                line,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+        Err(e) => return format!("{} | build FAILED: {}", line, e),
+    }
+
+    let target = env::var_os("CARGO_TARGET_DIR").map_or(root.join("target"), PathBuf::from);
+    let exe = target
+        .join("debug")
+        .join(format!("aoc{}", env::consts::EXE_SUFFIX));
+    let day_arg = day.to_string();
+    let year_arg = year.to_string();
+
+    let examples = match run_child(&exe, &["check", &day_arg, "--year", &year_arg]) {
+        Ok(examples) => examples,
+        Err(output) => {
+            let records = report::read_csv(&output);
+            let failed: Vec<String> = records
+                .iter()
+                .filter(|r| r.get("passed").is_some_and(|p| p == "false"))
+                .map(|r| {
+                    format!(
+                        "  {} part {}: expected {}, got {}",
+                        r["input"], r["part"], r["expected"], r["answer"]
+                    )
+                })
+                .collect();
+            if failed.is_empty() {
+                return format!("{} | examples FAILED\n{}", line, output);
+            }
+            return format!(
+                "{} | examples FAIL {}/{}\n{}",
+                line,
+                records.len() - failed.len(),
+                records.len(),
+                failed.join("\n")
+            );
+        }
+    };
+    let time: Duration = examples.iter().map(nanos).sum();
+    line += &format!(
+        " | examples {0}/{0} ok {1}",
+        examples.len(),
+        report::Human(time)
+    );
+
+    let answers = match run_child(&exe, &["run", &day_arg, "--year", &year_arg]) {
+        Ok(answers) => answers,
+        Err(output) => return format!("{} | input FAILED\n{}", line, output.trim_end()),
+    };
+    for record in &answers {
+        let (part, answer) = (&record["part"], &record["answer"]);
+        let change = match previous.insert(part.clone(), answer.clone()) {
+            None => String::new(),
+            Some(old) if old == *answer => " (same)".to_string(),
+            Some(old) => format!(" (was {})", old),
+        };
+        line += &format!(
+            " | part {} {}{} {}",
+            part,
+            answer,
+            change,
+            report::Human(nanos(record))
+        );
+    }
+    line
+}
+
+fn watch(args: &[String]) {
+    let year = year(args);
+    let day = parse_day(year, args.first());
+    let interval = Duration::from_millis(numeric_option(args, "--interval", 500));
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut watcher = Watcher::new(&[&root.join("src"), &root.join("inputs")], interval);
+    let mut previous = BTreeMap::new();
+
+    eprintln!(
+        "Watching src/ and inputs/ for {} day {} (Ctrl-C to stop)",
+        year, day
+    );
+    loop {
+        println!("{}", watch_once(year, day, root, &mut previous));
+        let changes = watcher.wait();
+        let names: Vec<String> = changes
+            .iter()
+            .map(|path| {
+                path.strip_prefix(root)
+                    .unwrap_or(path)
+                    .display()
+                    .to_string()
+            })
+            .collect();
+        eprintln!("changed: {}", names.join(", "));
+    }
+}
+
+fn image(args: &[String]) {
+    let year = year(args);
+    let day = parse_day(year, args.first());
+    let (_, input) = read_input(year, day, args);
+    let Some((extension, bytes)) = registry::image(year, day, &input, &params(args)) else {
+        eprintln!("No image for {} day {}", year, day);
+        process::exit(1);
+    };
+    let path = option(args, "--out")
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("day{:02}.{}", day, extension));
+    match fs::write(&path, bytes) {
+        Ok(_) => eprintln!("Wrote {}", path),
+        Err(e) => {
+            eprintln!("Failed to write {}: {}", path, e);
+            process::exit(1);
+        }
+    }
+}
+
+/// Options `svg` also takes as flags, each the `--param` of the same name.
+const SVG_FLAGS: &[&str] = &["plane", "angle", "connections", "size"];
+
+fn svg(args: &[String]) {
+    let year = year(args);
+    let day = parse_day(year, args.first());
+    let (_, input) = read_input(year, day, args);
+    let mut params = params(args);
+    for key in SVG_FLAGS {
+        if let Some(value) = option(args, &format!("--{}", key)) {
+            params.insert(key.to_string(), value.to_string());
+        }
+    }
+    if args.iter().any(|arg| arg == "--tree") {
+        params.insert("tree".to_string(), "true".to_string());
+    }
+    let Some(svg) = registry::svg(year, day, &input, &params) else {
+        eprintln!("No SVG for {} day {}", year, day);
+        process::exit(1);
+    };
+
+    let path = option(args, "--out")
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("day{:02}.svg", day));
+    match fs::write(&path, svg) {
+        Ok(_) => eprintln!("Wrote {}", path),
+        Err(e) => {
+            eprintln!("Failed to write {}: {}", path, e);
+            process::exit(1);
+        }
+    }
+}
+
+fn generate(args: &[String]) {
+    let year = year(args);
+    let day = parse_day(year, args.first());
+    let size: usize = numeric_option(args, "--size", 1000);
+    let seed: u64 = numeric_option(args, "--seed", 0);
+
+    let Some(input) = registry::generate(year, day, size, &mut Rng::new(seed)) else {
+        eprintln!("No generator for {} day {}", year, day);
+        process::exit(1);
+    };
+
+    match option(args, "--out") {
+        Some(path) => match fs::write(path, &input) {
+            Ok(_) => eprintln!("Wrote day {} input to {}", day, path),
+            Err(e) => {
+                eprintln!("Failed to write {}: {}", path, e);
+                process::exit(1);
+            }
+        },
+        None => print!("{}", input),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let Some(command) = args.first() else {
+        usage();
+    };
+
+    match command.as_str() {
+        "run" => run(&args[1..]),
+        "check" => check(&args[1..]),
+        "bench" => bench(&args[1..]),
+        "repl" => explore(&args[1..]),
+        "watch" => watch(&args[1..]),
+        "image" => image(&args[1..]),
+        "svg" => svg(&args[1..]),
+        "gen" => generate(&args[1..]),
+        _ => usage(),
+    }
+}
@@ -1,88 +1,39 @@
-use std::collections::{HashMap, HashSet};
-
-fn part1(input: &str) -> i64 {
-    let mut lines = input.lines();
-    let start_pos = lines
-        .next()
-        .expect("Error")
-        .find('S')
-        .expect("Error: No start position");
-
-    let (_, _, total_splits) = lines.fold(
-        (HashSet::from([start_pos]), HashSet::new(), 0),
-        |(mut active, mut buffer, current_splits), line| {
-            let line_splits = active
-                .drain()
-                .fold(0, |acc, pos| match line.as_bytes().get(pos) {
-                    Some(b'.') => {
-                        buffer.insert(pos);
-                        acc
-                    }
-                    Some(b'^') => {
-                        buffer.insert(pos - 1);
-                        buffer.insert(pos + 1);
-                        acc + 1
-                    }
-                    _ => todo!("What!"),
-                });
-
-            (buffer, active, current_splits + line_splits)
-        },
-    );
-
-    total_splits
-}
-
-fn part2(input: &str) -> usize {
-    let mut lines = input.lines();
-    let start_pos = lines
-        .next()
-        .expect("Error")
-        .find('S')
-        .expect("Error: No start position");
-
-    let (final_map, _) = lines.fold(
-        (HashMap::from([(start_pos, 1)]), HashMap::new()),
-        |(mut active, mut buffer), line| {
-            active
-                .drain()
-                .for_each(|(pos, count)| match line.as_bytes().get(pos) {
-                    Some(b'.') => {
-                        *buffer.entry(pos).or_insert(0) += count;
-                    }
-                    Some(b'^') => {
-                        *buffer.entry(pos - 1).or_insert(0) += count;
-                        *buffer.entry(pos + 1).or_insert(0) += count;
-                    }
-                    _ => todo!("What!"),
-                });
-
-            (buffer, active)
-        },
-    );
-
-    final_map.values().sum()
+use advent_of_code::bigint::BigUint;
+use advent_of_code::count::Mod;
+use advent_of_code::rng::Rng;
+use advent_of_code::y2025::day07::{
+    DEFAULT_SPLITTERS, Manifold, part1, part2, simulate, timelines,
+};
+
+fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    let pos = args.iter().position(|arg| arg == flag)?;
+    args.get(pos + 1).map(String::as_str)
 }
 
 fn main() {
-    let input = include_str!("../../inputs/day07.txt");
-    println!("Part 1: {}", part1(input));
-    println!("Part 2: {}", part2(input));
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
+    let input = include_str!("../../inputs/2025/day07.txt");
+    let args: Vec<String> = std::env::args().skip(1).collect();
 
-    #[test]
-    fn test_part1() {
-        let input = include_str!("../../inputs/day07_test.txt");
-        assert_eq!(part1(input), 21);
+    println!("Part 1: {}", part1(input));
+    match flag_value(&args, "--count") {
+        Some("u128") => println!("Part 2: {}", timelines::<u128>(input)),
+        Some("big") => println!("Part 2: {}", timelines::<BigUint>(input)),
+        Some("mod") => println!("Part 2: {}", timelines::<Mod<1_000_000_007>>(input)),
+        _ => println!("Part 2: {}", part2(input)),
     }
 
-    #[test]
-    fn test_part2() {
-        let input = include_str!("../../inputs/day07_test.txt");
-        assert_eq!(part2(input), 40);
+    let manifold = Manifold::parse(input, DEFAULT_SPLITTERS).unwrap_or_else(|e| panic!("{}", e));
+    let field = simulate::<Mod<1_000_000_007>>(input);
+    if args.iter().any(|arg| arg == "--render") {
+        print!("{}", manifold.render(&field));
+    }
+    if let Some(limit) = flag_value(&args, "--timelines").and_then(|n| n.parse().ok()) {
+        for timeline in manifold.enumerate_timelines(limit) {
+            println!("{:?}", timeline);
+        }
+    }
+    if let Some(seed) = flag_value(&args, "--sample").and_then(|n| n.parse().ok()) {
+        let timeline = manifold.sample_timeline(&field, &mut Rng::new(seed));
+        println!("{:?}", timeline);
     }
 }
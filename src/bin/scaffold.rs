@@ -1,66 +1,291 @@
+use advent_of_code::registry::{self, DEFAULT_YEAR};
 use std::env;
 use std::fs;
 use std::path::Path;
 use std::process;
 
-const TEMPLATE: &str = r#"fn part1(input: &str) -> String {
+const TEMPLATE: &str = r#"pub fn part1(input: &str) -> String {
     "todo".to_string()
 }
 
-fn part2(input: &str) -> String {
+pub fn part2(input: &str) -> String {
     "todo".to_string()
 }
+"#;
+
+const FIXTURE_TEMPLATE: &str = r#"
+[[example]]
+day = DAY
+input = "dayDAY_test.txt"
+# part1 =
+# part2 =
+"#;
+
+const BIN_TEMPLATE: &str = r#"use advent_of_code::yYEAR::dayDAY::{part1, part2};
 
 fn main() {
-    let input = include_str!("../../inputs/dayDAY.txt");
+    let input = include_str!("../../inputs/YEAR/dayDAY.txt");
     println!("Part 1: {}", part1(input));
     println!("Part 2: {}", part2(input));
 }
+"#;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+const YEAR_TEMPLATE: &str = r#"//! Advent of Code YEAR.
 
-    #[test]
-    fn test_part1() {
-        let input = include_str!("../../inputs/dayDAY_test.txt");
-        assert_eq!(part1(input), "todo");
-    }
+use crate::registry::{Answer, Params};
 
-    #[test]
-    fn test_part2() {
-        let input = include_str!("../../inputs/dayDAY_test.txt");
-        assert_eq!(part2(input), "todo");
-    }
+/// Solves one part of one day, or returns `None` if there is no such day or
+/// part.
+pub fn solve(day: u32, part: u32, input: &str, _params: &Params) -> Option<Answer> {
+    let answer = match (day, part) {
+        _ => return None,
+    };
+    Some(answer)
 }
 "#;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() != 2 {
-        eprintln!("Usage: cargo run --bin scaffold <day>");
-        eprintln!("Example: cargo run --bin scaffold 1");
+/// Inserts `line` into the file at `path` before the first line (after
+/// `start`, if given) for which `before` holds, reporting what happened.
+fn insert_line(path: &Path, start: Option<&str>, line: &str, before: impl Fn(&str) -> bool) {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            eprintln!("Failed to read {}: {}", path.display(), e);
+            return;
+        }
+    };
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let from = match start {
+        Some(start) => lines
+            .iter()
+            .position(|l| l.starts_with(start))
+            .unwrap_or(lines.len()),
+        None => 0,
+    };
+    match lines[from..].iter().position(|l| before(l)) {
+        Some(position) => lines.insert(from + position, line),
+        None => {
+            eprintln!(
+                "Could not find where to add {:?} in {}",
+                line.trim(),
+                path.display()
+            );
+            return;
+        }
+    }
+    if let Err(e) = fs::write(path, format!("{}\n", lines.join("\n"))) {
+        eprintln!("Failed to update {}: {}", path.display(), e);
+    }
+}
+
+/// Creates `src/y<year>/mod.rs` for a year that has no solvers yet, and
+/// hooks it into `src/lib.rs` and `registry::solve`.
+fn register_year(year: u32) {
+    let module = format!("y{}", year);
+    let dir = Path::new("src").join(&module);
+    if dir.join("mod.rs").exists() {
+        return;
+    }
+    if let Err(e) = fs::create_dir_all(&dir).and_then(|_| {
+        fs::write(
+            dir.join("mod.rs"),
+            YEAR_TEMPLATE.replace("YEAR", &year.to_string()),
+        )
+    }) {
+        eprintln!("Failed to create {}: {}", dir.join("mod.rs").display(), e);
         process::exit(1);
     }
+    println!("Created {}", dir.join("mod.rs").display());
 
-    let day: u32 = match args[1].parse() {
-        Ok(d) if d >= 1 && d <= 25 => d,
-        _ => {
-            eprintln!("Day must be between 1 and 25");
-            process::exit(1);
+    // Module declarations in lib.rs are kept in alphabetical order, so the
+    // new one goes before the first that sorts after it or ends the list.
+    let declaration = format!("pub mod {};", module);
+    insert_line(
+        &Path::new("src").join("lib.rs"),
+        None,
+        &declaration,
+        |line| {
+            !(line.starts_with("pub mod ") && line.ends_with(';')) || line > declaration.as_str()
+        },
+    );
+
+    let registry = Path::new("src").join("registry.rs");
+    insert_line(
+        &registry,
+        None,
+        &format!("use crate::{};", module),
+        |line| {
+            line.starts_with("use crate::y") && line > format!("use crate::{};", module).as_str()
+                || line.starts_with("use std::")
+        },
+    );
+    insert_line(
+        &registry,
+        Some("pub fn solve("),
+        &format!(
+            "        {} => {}::solve(day, part, input, params),",
+            year, module
+        ),
+        |line| line.trim() == "_ => None,",
+    );
+    match fs::read_to_string(&registry) {
+        Ok(contents) => {
+            let years = contents
+                .lines()
+                .find_map(|line| line.strip_prefix("pub const YEARS: &[u32] = &["))
+                .and_then(|rest| rest.strip_suffix("];"));
+            if let Some(years) = years {
+                let mut list: Vec<u32> = years
+                    .split(',')
+                    .filter_map(|y| y.trim().parse().ok())
+                    .collect();
+                list.push(year);
+                list.sort();
+                let list: Vec<String> = list.iter().map(u32::to_string).collect();
+                let updated = contents.replacen(
+                    &format!("pub const YEARS: &[u32] = &[{}];", years),
+                    &format!("pub const YEARS: &[u32] = &[{}];", list.join(", ")),
+                    1,
+                );
+                if let Err(e) = fs::write(&registry, updated) {
+                    eprintln!("Failed to update {}: {}", registry.display(), e);
+                }
+            }
+        }
+        Err(e) => eprintln!("Failed to read {}: {}", registry.display(), e),
+    }
+    println!(
+        "Registered {} in src/lib.rs and {}",
+        module,
+        registry.display()
+    );
+}
+
+/// Adds `pub mod <module_name>;` after the last module declaration in
+/// `src/y<year>/mod.rs`, and arms for both parts to its `solve`.
+fn register_module(year: u32, day: u32, module_name: &str) {
+    let mod_path = Path::new("src").join(format!("y{}", year)).join("mod.rs");
+    let declaration = format!("pub mod {};", module_name);
+    let contents = match fs::read_to_string(&mod_path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            eprintln!("Failed to read {}: {}", mod_path.display(), e);
+            return;
         }
     };
+    if contents.lines().any(|line| line == declaration) {
+        return;
+    }
+
+    let mut lines: Vec<&str> = contents.lines().collect();
+    match lines.iter().rposition(|line| line.starts_with("pub mod ")) {
+        Some(i) => lines.insert(i + 1, &declaration),
+        None => {
+            // The first module goes after the doc comment, in its own block.
+            let position = lines
+                .iter()
+                .position(|line| !line.starts_with("//!") && !line.is_empty())
+                .unwrap_or(lines.len());
+            lines.insert(position, "");
+            lines.insert(position, &declaration);
+        }
+    }
+
+    let arms = [1, 2].map(|part| {
+        format!(
+            "        ({}, {}) => Answer::from({}::part{}(input)),",
+            day, part, module_name, part
+        )
+    });
+    match lines
+        .iter()
+        .position(|line| line.trim() == "_ => return None,")
+    {
+        Some(position) => {
+            lines.insert(position, &arms[1]);
+            lines.insert(position, &arms[0]);
+        }
+        None => eprintln!(
+            "Could not find solve in {}; add {} by hand",
+            mod_path.display(),
+            module_name
+        ),
+    }
+
+    match fs::write(&mod_path, format!("{}\n", lines.join("\n"))) {
+        Ok(_) => println!("Registered {} in {}", module_name, mod_path.display()),
+        Err(e) => eprintln!("Failed to update {}: {}", mod_path.display(), e),
+    }
+}
+
+/// Appends an example for the day to `inputs/<year>/fixtures.toml`, with its
+/// answers left for you to fill in.
+fn add_fixture(year: u32, day: u32) {
+    let path = Path::new("inputs")
+        .join(year.to_string())
+        .join("fixtures.toml");
+    let mut manifest = fs::read_to_string(&path).unwrap_or_default();
+    if manifest.contains(&format!("\"day{:02}_test.txt\"", day)) {
+        return;
+    }
+    manifest.push_str(
+        &FIXTURE_TEMPLATE
+            .replace("dayDAY", &format!("day{:02}", day))
+            .replace("DAY", &day.to_string()),
+    );
+    match fs::write(&path, manifest) {
+        Ok(_) => println!("Added day {} example to {}", day, path.display()),
+        Err(e) => eprintln!("Failed to update {}: {}", path.display(), e),
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("Usage: cargo run --bin scaffold <day> [--year Y]");
+    eprintln!("Example: cargo run --bin scaffold 1 --year 2024");
+    process::exit(1);
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let year: u32 = match args.as_slice() {
+        [_] => DEFAULT_YEAR,
+        [_, flag, year] if flag == "--year" => year.parse().unwrap_or_else(|_| usage()),
+        _ => usage(),
+    };
+    let Ok(day) = args[0].parse::<u32>() else {
+        usage();
+    };
+    if let Err(e) = registry::validate(year, day) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
 
     let day_padded = format!("{:02}", day);
     let module_name = format!("day{}", day_padded);
     let input_filename = format!("day{}.txt", day_padded);
     let test_input_filename = format!("day{}_test.txt", day_padded);
+    // 2025's binaries came first and keep their short names.
+    let bin_name = if year == DEFAULT_YEAR {
+        module_name.clone()
+    } else {
+        format!("y{}_{}", year, module_name)
+    };
 
     let rs_path = Path::new("src")
-        .join("bin")
+        .join(format!("y{}", year))
         .join(format!("{}.rs", module_name));
-    let input_path = Path::new("inputs").join(&input_filename);
-    let test_input_path = Path::new("inputs").join(&test_input_filename);
+    let bin_path = Path::new("src")
+        .join("bin")
+        .join(format!("{}.rs", bin_name));
+    let inputs_dir = Path::new("inputs").join(year.to_string());
+    let input_path = inputs_dir.join(&input_filename);
+    let test_input_path = inputs_dir.join(&test_input_filename);
+
+    if let Err(e) = fs::create_dir_all(&inputs_dir) {
+        eprintln!("Failed to create {}: {}", inputs_dir.display(), e);
+        process::exit(1);
+    }
+    register_year(year);
 
     // Create input file if it doesn't exist
     if !input_path.exists() {
@@ -102,6 +327,20 @@ fn main() {
         Err(e) => eprintln!("Failed to create rust file: {}", e),
     }
 
+    if !bin_path.exists() {
+        let bin_code = BIN_TEMPLATE
+            .replace("dayDAY", &module_name)
+            .replace("YEAR", &year.to_string());
+        match fs::write(&bin_path, bin_code) {
+            Ok(_) => println!("Created rust file: {}", bin_path.display()),
+            Err(e) => eprintln!("Failed to create rust file: {}", e),
+        }
+    }
+
+    register_module(year, day, &module_name);
+    add_fixture(year, day);
+
     println!("Done! You can run the day with:");
-    println!("cargo run --bin {}", module_name);
+    println!("cargo run --bin {}", bin_name);
+    println!("cargo run --bin aoc -- run {} --year {}", day, year);
 }
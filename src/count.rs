@@ -0,0 +1,150 @@
+use crate::bigint::BigUint;
+use std::fmt;
+
+/// A number type that path and timeline counts can be accumulated in.
+pub trait Count: Clone + fmt::Debug + fmt::Display + PartialEq {
+    /// Name used in overflow messages.
+    const NAME: &'static str;
+    /// The type to switch to when this one overflows, if any.
+    const WIDER: Option<&'static str>;
+
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    /// Adds `other` into `self`, reporting an overflow instead of wrapping.
+    fn try_add_assign(&mut self, other: &Self) -> Result<(), Overflow> {
+        *self = self.checked_add(other).ok_or(Overflow {
+            type_name: Self::NAME,
+            suggestion: Self::WIDER,
+        })?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    pub type_name: &'static str,
+    pub suggestion: Option<&'static str>,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "count overflowed {}", self.type_name)?;
+        if let Some(wider) = self.suggestion {
+            write!(f, ", try {} instead", wider)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+macro_rules! impl_count {
+    ($t:ty, $wider:expr) => {
+        impl Count for $t {
+            const NAME: &'static str = stringify!($t);
+            const WIDER: Option<&'static str> = $wider;
+
+            fn zero() -> Self {
+                0
+            }
+
+            fn one() -> Self {
+                1
+            }
+
+            fn checked_add(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_add(*self, *other)
+            }
+        }
+    };
+}
+
+impl_count!(u32, Some("u64"));
+impl_count!(u64, Some("u128"));
+impl_count!(usize, Some("u128"));
+impl_count!(u128, Some("BigUint"));
+
+impl Count for BigUint {
+    const NAME: &'static str = "BigUint";
+    const WIDER: Option<&'static str> = None;
+
+    fn zero() -> Self {
+        BigUint::zero()
+    }
+
+    fn one() -> Self {
+        BigUint::from(1u64)
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+}
+
+/// Integer modulo `P`, for puzzles that only want the answer mod a prime.
+/// `Mod<0>` fails to compile as soon as it is used:
+///
+/// ```compile_fail
+/// advent_of_code::count::Mod::<0>::new(1);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Mod<const P: u64>(u64);
+
+impl<const P: u64> Mod<P> {
+    pub fn new(n: u64) -> Self {
+        const { assert!(P != 0, "Mod needs a nonzero modulus") };
+        Self(n % P)
+    }
+
+    pub fn value(self) -> u64 {
+        self.0
+    }
+}
+
+impl<const P: u64> fmt::Display for Mod<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<const P: u64> Count for Mod<P> {
+    const NAME: &'static str = "Mod";
+    const WIDER: Option<&'static str> = None;
+
+    fn zero() -> Self {
+        Self(0)
+    }
+
+    fn one() -> Self {
+        Self::new(1)
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        const { assert!(P != 0, "Mod needs a nonzero modulus") };
+        Some(Self(
+            ((self.0 as u128 + other.0 as u128) % P as u128) as u64,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overflow_suggests_wider_type() {
+        let mut n = u64::MAX;
+        let err = n.try_add_assign(&1).unwrap_err();
+        assert_eq!(err.to_string(), "count overflowed u64, try u128 instead");
+        assert_eq!(n, u64::MAX);
+    }
+
+    #[test]
+    fn test_mod_wraps() {
+        let mut n = Mod::<7>::new(5);
+        n.try_add_assign(&Mod::new(4)).unwrap();
+        assert_eq!(n.value(), 2);
+    }
+}
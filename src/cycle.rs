@@ -0,0 +1,116 @@
+//! Cycle detection for simulations that repeat: where a sequence of states
+//! starts looping and how long the loop is, found with Brent's algorithm
+//! while holding only two states at a time, and [`state_after`] to jump a
+//! simulation forward by far more steps than could be run.
+
+/// The states `initial, step(initial), ...` enter a loop of `length` states
+/// after `start` steps; a simulation that settles down has length 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<S> {
+    pub start: usize,
+    pub length: usize,
+    /// The state after `start` steps, the first one that repeats.
+    pub entry: S,
+}
+
+/// Finds where the states reached from `initial` by `step` start to repeat.
+/// Loops forever if they never do.
+pub fn find_cycle<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle<S> {
+    // Brent: the tortoise waits at powers of two while the hare runs ahead,
+    // until the hare comes back round to it.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare `length` steps ahead, the two first meet where the
+    // cycle starts.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle {
+        start,
+        length,
+        entry: tortoise,
+    }
+}
+
+/// The state after `n` steps from `initial`, running at most a few times
+/// the length of the lead-in and the cycle however large `n` is.
+pub fn state_after<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let cycle = find_cycle(initial.clone(), &mut step);
+    let (mut state, remaining) = if n < cycle.start {
+        (initial, n)
+    } else {
+        (cycle.entry, (n - cycle.start) % cycle.length)
+    };
+    for _ in 0..remaining {
+        state = step(&state);
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn step(x: &u32) -> u32 {
+        (x * x + 1) % 1009
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        for initial in 0..200 {
+            let mut seen = HashMap::new();
+            let mut states = Vec::new();
+            let mut state = initial;
+            while !seen.contains_key(&state) {
+                seen.insert(state, states.len());
+                states.push(state);
+                state = step(&state);
+            }
+            let start = seen[&state];
+
+            let cycle = find_cycle(initial, step);
+            assert_eq!(cycle.start, start, "from {}", initial);
+            assert_eq!(cycle.length, states.len() - start, "from {}", initial);
+            assert_eq!(cycle.entry, state);
+
+            for n in [0, 1, start, states.len(), 3 * states.len() + 1] {
+                let expected = match n < states.len() {
+                    true => states[n],
+                    false => states[start + (n - start) % cycle.length],
+                };
+                assert_eq!(state_after(initial, step, n), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        let halve = |x: &u64| x / 2;
+        let cycle = find_cycle(1000, halve);
+        assert_eq!((cycle.start, cycle.length, cycle.entry), (10, 1, 0));
+        assert_eq!(state_after(1000, halve, 1_000_000_000), 0);
+        assert_eq!(state_after(1000, halve, 3), 125);
+    }
+}
@@ -0,0 +1,239 @@
+//! Differential testing: run a slow, obviously correct reference solver and
+//! the real one on many generated inputs and compare the answers. The first
+//! mismatch is shrunk to a minimal input and saved under
+//! `target/regressions/`. Every run first replays the inputs kept under
+//! `inputs/regressions/`; set `SAVE_REGRESSIONS` to save new ones there.
+
+use crate::rng::Rng;
+use std::env;
+use std::fmt::Debug;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+
+pub struct Differential<G> {
+    name: String,
+    generate: G,
+    cases: u64,
+    max_size: usize,
+    seed: u64,
+    separator: char,
+}
+
+/// What a solver produced: its answer, or the message it panicked with.
+type Outcome<T> = Result<T, String>;
+
+fn run<T>(solver: &impl Fn(&str) -> T, input: &str) -> Outcome<T> {
+    panic::catch_unwind(AssertUnwindSafe(|| solver(input))).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "panic".to_string())
+    })
+}
+
+impl<G: Fn(usize, &mut Rng) -> String> Differential<G> {
+    /// `name` identifies the solver pair, e.g. `day01_part2`, and prefixes
+    /// its regression files.
+    pub fn new(name: &str, generate: G) -> Self {
+        Self {
+            name: name.to_string(),
+            generate,
+            cases: 200,
+            max_size: 20,
+            seed: 0,
+            separator: '\n',
+        }
+    }
+
+    pub fn cases(mut self, cases: u64) -> Self {
+        self.cases = cases;
+        self
+    }
+
+    /// Inputs are generated with sizes cycling through `1..=max_size`.
+    pub fn max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size.max(1);
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// What a failing input is split on into records while shrinking: lines
+    /// by default, `','` for inputs that are one comma separated line.
+    pub fn separator(mut self, separator: char) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Panics with the shrunk input on the first disagreement.
+    pub fn check<T, R, O>(&self, reference: R, optimized: O)
+    where
+        T: PartialEq + Debug,
+        R: Fn(&str) -> T,
+        O: Fn(&str) -> T,
+    {
+        let disagree = |input: &str| run(&reference, input) != run(&optimized, input);
+
+        for (path, input) in self.regressions() {
+            if disagree(&input) {
+                panic!(
+                    "{}: regression {} still fails\nreference: {:?}\noptimized: {:?}",
+                    self.name,
+                    path.display(),
+                    run(&reference, &input),
+                    run(&optimized, &input)
+                );
+            }
+        }
+
+        for case in 0..self.cases {
+            let seed = self.seed.wrapping_add(case);
+            let size = 1 + (case as usize % self.max_size);
+            let input = (self.generate)(size, &mut Rng::new(seed));
+            if !disagree(&input) {
+                continue;
+            }
+
+            let minimal = shrink(&input, self.separator, disagree);
+            let path = self.save(seed, &minimal);
+            panic!(
+                "{}: mismatch for seed {} (size {}), shrunk input saved to {}\n{}\nreference: {:?}\noptimized: {:?}",
+                self.name,
+                seed,
+                size,
+                path.display(),
+                minimal,
+                run(&reference, &minimal),
+                run(&optimized, &minimal)
+            );
+        }
+    }
+
+    fn regressions_dir() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("inputs")
+            .join("regressions")
+    }
+
+    fn regressions(&self) -> Vec<(PathBuf, String)> {
+        let prefix = format!("{}_", self.name);
+        let Ok(entries) = fs::read_dir(Self::regressions_dir()) else {
+            return Vec::new();
+        };
+        let mut found: Vec<(PathBuf, String)> = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(&prefix) && name.ends_with(".txt"))
+            })
+            .filter_map(|path| Some((path.clone(), fs::read_to_string(&path).ok()?)))
+            .collect();
+        found.sort();
+        found
+    }
+
+    /// Where new failures go: `inputs/regressions/` when opted in, so they
+    /// get replayed and can be committed, and the target directory
+    /// otherwise, to keep test runs from touching the source tree.
+    fn save_dir() -> PathBuf {
+        if env::var_os("SAVE_REGRESSIONS").is_some() {
+            return Self::regressions_dir();
+        }
+        option_env!("CARGO_TARGET_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("target"))
+            .join("regressions")
+    }
+
+    fn save(&self, seed: u64, input: &str) -> PathBuf {
+        let dir = Self::save_dir();
+        let path = dir.join(format!("{}_seed{}.txt", self.name, seed));
+        if let Err(e) = fs::create_dir_all(&dir).and_then(|_| fs::write(&path, input)) {
+            eprintln!("Failed to save regression {}: {}", path.display(), e);
+        }
+        path
+    }
+}
+
+/// Shrinks `input` while `still_fails` holds by deleting chunks of the
+/// records `separator` splits it into, down to single records. A trailing
+/// newline is kept.
+pub fn shrink(input: &str, separator: char, mut still_fails: impl FnMut(&str) -> bool) -> String {
+    let terminator = if input.ends_with('\n') { "\n" } else { "" };
+    let join = |units: &[&str]| format!("{}{}", units.join(&separator.to_string()), terminator);
+
+    let mut units: Vec<&str> = input.trim_end_matches('\n').split(separator).collect();
+    let mut chunk = units.len().div_ceil(2);
+    while chunk > 0 && units.len() > 1 {
+        let mut start = 0;
+        let mut progressed = false;
+        while start < units.len() {
+            let end = (start + chunk).min(units.len());
+            let candidate: Vec<&str> = units[..start]
+                .iter()
+                .chain(&units[end..])
+                .copied()
+                .collect();
+            if !candidate.is_empty() && still_fails(&join(&candidate)) {
+                units = candidate;
+                progressed = true;
+            } else {
+                start = end;
+            }
+        }
+        if !progressed {
+            chunk /= 2;
+        }
+    }
+    join(&units)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shrink_lines() {
+        let input = "1\n2\n3\n13\n4\n5\n";
+        let minimal = shrink(input, '\n', |s| s.lines().any(|l| l == "13"));
+        assert_eq!(minimal, "13\n");
+
+        // A single line is one record, however many commas it has.
+        let minimal = shrink("1,2,3\n", '\n', |s| s.contains('2'));
+        assert_eq!(minimal, "1,2,3\n");
+    }
+
+    #[test]
+    fn test_failures_stay_out_of_inputs() {
+        if env::var_os("SAVE_REGRESSIONS").is_some() {
+            return;
+        }
+        let differential =
+            Differential::new("differential_selftest", |_, _: &mut Rng| "1\n".to_string()).cases(1);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            differential.check(|_| 0, |input| input.lines().count());
+        }));
+        assert!(result.is_err());
+
+        let name = "differential_selftest_seed0.txt";
+        let saved = Differential::<fn(usize, &mut Rng) -> String>::save_dir().join(name);
+        assert_eq!(fs::read_to_string(&saved).unwrap(), "1\n");
+        fs::remove_file(saved).unwrap();
+        let source = Differential::<fn(usize, &mut Rng) -> String>::regressions_dir();
+        assert!(!source.join(name).exists());
+    }
+
+    #[test]
+    fn test_shrink_items() {
+        let input = "1-2,3-4,5-6,7-8\n";
+        let minimal = shrink(input, ',', |s| s.contains("3-4") && s.contains("7-8"));
+        assert_eq!(minimal, "3-4,7-8\n");
+    }
+}
@@ -0,0 +1,83 @@
+//! The hook behind `--explain`: solvers note the steps that led to their
+//! answer, such as each rotation of day 1's dial, and a [`Trace`] keeps the
+//! first few. Notes are formatted lazily, so a disabled trace costs a branch.
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trace {
+    limit: usize,
+    lines: Vec<String>,
+    dropped: usize,
+}
+
+impl Trace {
+    /// Keeps the first `limit` notes and counts the rest.
+    pub fn new(limit: usize) -> Self {
+        Trace {
+            limit,
+            lines: Vec::new(),
+            dropped: 0,
+        }
+    }
+
+    /// A trace that keeps nothing, for solving without explaining.
+    pub fn off() -> Self {
+        Trace::new(0)
+    }
+
+    /// Whether notes are still being kept, for solvers that would otherwise
+    /// collect details only to explain them.
+    pub fn enabled(&self) -> bool {
+        self.lines.len() < self.limit
+    }
+
+    pub fn note(&mut self, note: impl FnOnce() -> String) {
+        if self.enabled() {
+            self.lines.push(note());
+        } else if self.limit > 0 {
+            self.dropped += 1;
+        }
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Notes left out because the limit was reached.
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+}
+
+/// `items` joined with commas, cut short after `max` of them.
+pub fn list<T: ToString>(items: &[T], max: usize) -> String {
+    let shown: Vec<String> = items.iter().take(max).map(T::to_string).collect();
+    match items.len().saturating_sub(max) {
+        0 => shown.join(", "),
+        more => format!("{} and {} more", shown.join(", "), more),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limit() {
+        let mut trace = Trace::new(2);
+        for i in 0..5 {
+            trace.note(|| format!("step {}", i));
+        }
+        assert_eq!(trace.lines(), ["step 0", "step 1"]);
+        assert_eq!(trace.dropped(), 3);
+
+        let mut off = Trace::off();
+        off.note(|| panic!("a disabled trace formats nothing"));
+        assert_eq!((off.lines().len(), off.dropped()), (0, 0));
+    }
+
+    #[test]
+    fn test_list() {
+        assert_eq!(list(&[1, 2, 3], 5), "1, 2, 3");
+        assert_eq!(list(&[1, 2, 3], 2), "1, 2 and 1 more");
+    }
+}
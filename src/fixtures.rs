@@ -0,0 +1,412 @@
+//! Example inputs and their expected answers, listed per year in
+//! `inputs/<year>/fixtures.toml` instead of being hard-coded in each day's
+//! tests, so adding an example is a data change.
+//!
+//! The manifest uses the small part of TOML it needs: `[[example]]` tables
+//! whose values are integers or basic strings.
+//!
+//! ```toml
+//! [[example]]
+//! day = 8
+//! input = "day08_test.txt"
+//! params.connections = 10
+//! part1 = 40
+//! part2 = 25272
+//! ```
+//!
+//! `input` names a file next to the manifest, while `text` gives the input
+//! inline. `params.<name>` keys are passed to the solver (see
+//! [`registry::solve`]), and
+//! parts without an expected answer are not checked.
+
+use crate::registry::{self, Answer, Params};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(String),
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    /// Line of the `[[example]]` header, to point at in messages.
+    pub line: usize,
+    pub day: u32,
+    pub source: Source,
+    pub params: Params,
+    /// Expected answers by part number.
+    pub expected: BTreeMap<u32, Answer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManifestError {
+    Syntax { line: usize },
+    OutsideExample { line: usize },
+    UnknownKey { line: usize, key: String },
+    DuplicateKey { line: usize, key: String },
+    InvalidValue { line: usize, key: String },
+    MissingKey { line: usize, key: &'static str },
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ManifestError::Syntax { line } => write!(f, "line {}: syntax error", line),
+            ManifestError::OutsideExample { line } => {
+                write!(f, "line {}: key outside an [[example]] table", line)
+            }
+            ManifestError::UnknownKey { line, key } => {
+                write!(f, "line {}: unknown key {}", line, key)
+            }
+            ManifestError::DuplicateKey { line, key } => {
+                write!(f, "line {}: {} is set twice", line, key)
+            }
+            ManifestError::InvalidValue { line, key } => {
+                write!(f, "line {}: invalid value for {}", line, key)
+            }
+            ManifestError::MissingKey { line, key } => {
+                write!(f, "example at line {} has no {}", line, key)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ManifestError {}
+
+/// Where puzzle inputs and manifests live, one directory per year.
+pub fn inputs_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs")
+}
+
+impl Fixture {
+    pub fn input(&self, dir: &Path) -> io::Result<String> {
+        match &self.source {
+            Source::File(name) => fs::read_to_string(dir.join(name)),
+            Source::Text(text) => Ok(text.clone()),
+        }
+    }
+
+    /// A short label for messages, e.g. `day08_test.txt` or
+    /// `day 1 inline example (line 12)`.
+    pub fn name(&self) -> String {
+        match &self.source {
+            Source::File(name) => name.clone(),
+            Source::Text(_) => format!("day {} inline example (line {})", self.day, self.line),
+        }
+    }
+}
+
+/// A value as written in the manifest: integers keep their digits, strings
+/// have their escapes resolved.
+enum Value {
+    Integer(String),
+    String(String),
+}
+
+impl Value {
+    fn into_text(self) -> String {
+        match self {
+            Value::Integer(s) | Value::String(s) => s,
+        }
+    }
+}
+
+/// Parses a value and anything after it, which may only be a comment.
+fn parse_value(s: &str) -> Option<Value> {
+    let s = s.trim_start();
+    let (value, rest) = if let Some(quoted) = s.strip_prefix('"') {
+        let mut value = String::new();
+        let mut chars = quoted.char_indices();
+        let end = loop {
+            match chars.next()? {
+                (i, '"') => break i + 1,
+                (_, '\\') => value.push(match chars.next()?.1 {
+                    'n' => '\n',
+                    't' => '\t',
+                    '\\' => '\\',
+                    '"' => '"',
+                    _ => return None,
+                }),
+                (_, c) => value.push(c),
+            }
+        };
+        (Value::String(value), &quoted[end..])
+    } else {
+        let end = s
+            .find(|c: char| c.is_whitespace() || c == '#')
+            .unwrap_or(s.len());
+        let literal = &s[..end];
+        let digits = literal.strip_prefix(['-', '+']).unwrap_or(literal);
+        if digits.is_empty()
+            || !digits.bytes().all(|b| b.is_ascii_digit() || b == b'_')
+            || digits.starts_with('_')
+            || digits.ends_with('_')
+        {
+            return None;
+        }
+        let integer = literal.trim_start_matches('+').replace('_', "");
+        (Value::Integer(integer), &s[end..])
+    };
+    let rest = rest.trim_start();
+    (rest.is_empty() || rest.starts_with('#')).then_some(value)
+}
+
+/// The example being read, before its required keys are checked.
+#[derive(Default)]
+struct Partial {
+    line: usize,
+    day: Option<u32>,
+    source: Option<Source>,
+    params: Params,
+    expected: BTreeMap<u32, Answer>,
+}
+
+impl Partial {
+    fn finish(self) -> Result<Fixture, ManifestError> {
+        let line = self.line;
+        Ok(Fixture {
+            line,
+            day: self
+                .day
+                .ok_or(ManifestError::MissingKey { line, key: "day" })?,
+            source: self.source.ok_or(ManifestError::MissingKey {
+                line,
+                key: "input or text",
+            })?,
+            params: self.params,
+            expected: self.expected,
+        })
+    }
+
+    fn set(&mut self, line: usize, key: &str, value: Value) -> Result<(), ManifestError> {
+        let duplicate = || ManifestError::DuplicateKey {
+            line,
+            key: key.to_string(),
+        };
+        let invalid = || ManifestError::InvalidValue {
+            line,
+            key: key.to_string(),
+        };
+        match key {
+            "day" => {
+                let Value::Integer(day) = value else {
+                    return Err(invalid());
+                };
+                let day = day.parse().map_err(|_| invalid())?;
+                if self.day.replace(day).is_some() {
+                    return Err(duplicate());
+                }
+            }
+            "input" | "text" => {
+                let Value::String(s) = value else {
+                    return Err(invalid());
+                };
+                let source = if key == "input" {
+                    Source::File(s)
+                } else {
+                    Source::Text(s)
+                };
+                if self.source.replace(source).is_some() {
+                    return Err(duplicate());
+                }
+            }
+            _ => {
+                if let Some(name) = key.strip_prefix("params.") {
+                    if self
+                        .params
+                        .insert(name.to_string(), value.into_text())
+                        .is_some()
+                    {
+                        return Err(duplicate());
+                    }
+                } else if let Some(part) = key.strip_prefix("part").and_then(|n| n.parse().ok()) {
+                    let answer = value.into_text().parse().unwrap_or_else(|e| match e {});
+                    if self.expected.insert(part, answer).is_some() {
+                        return Err(duplicate());
+                    }
+                } else {
+                    return Err(ManifestError::UnknownKey {
+                        line,
+                        key: key.to_string(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn parse(manifest: &str) -> Result<Vec<Fixture>, ManifestError> {
+    let mut fixtures = Vec::new();
+    let mut current: Option<Partial> = None;
+
+    for (i, raw) in manifest.lines().enumerate() {
+        let line = i + 1;
+        let text = raw.trim();
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+
+        if let Some(header) = text.strip_prefix("[[") {
+            let header = header.split('#').next().unwrap_or("").trim_end();
+            if header.strip_suffix("]]").map(str::trim) != Some("example") {
+                return Err(ManifestError::Syntax { line });
+            }
+            if let Some(done) = current.replace(Partial {
+                line,
+                ..Partial::default()
+            }) {
+                fixtures.push(done.finish()?);
+            }
+            continue;
+        }
+
+        let Some((key, value)) = text.split_once('=') else {
+            return Err(ManifestError::Syntax { line });
+        };
+        let key = key.trim();
+        let Some(value) = parse_value(value) else {
+            return Err(ManifestError::InvalidValue {
+                line,
+                key: key.to_string(),
+            });
+        };
+        let Some(example) = current.as_mut() else {
+            return Err(ManifestError::OutsideExample { line });
+        };
+        example.set(line, key, value)?;
+    }
+
+    if let Some(done) = current {
+        fixtures.push(done.finish()?);
+    }
+    Ok(fixtures)
+}
+
+/// Reads and parses `inputs/<year>/fixtures.toml`. A year without a
+/// manifest has no examples.
+pub fn load(year: u32) -> Result<Vec<Fixture>, String> {
+    let path = registry::year_dir(year).join("fixtures.toml");
+    let manifest = match fs::read_to_string(&path) {
+        Ok(manifest) => manifest,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("failed to read {}: {}", path.display(), e)),
+    };
+    parse(&manifest).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Runs every part of one of `year`'s fixtures that has an expected answer
+/// and describes each one that came out differently.
+pub fn check(year: u32, fixture: &Fixture) -> Vec<String> {
+    let input = match fixture.input(&registry::year_dir(year)) {
+        Ok(input) => input,
+        Err(e) => return vec![format!("{}: {}", fixture.name(), e)],
+    };
+    let mut failures = Vec::new();
+    for (&part, expected) in &fixture.expected {
+        match registry::solve(year, fixture.day, part, &input, &fixture.params) {
+            Some(answer) if answer == *expected => {}
+            Some(answer) => failures.push(format!(
+                "{} part {}: expected {}, got {} (line {})",
+                fixture.name(),
+                part,
+                expected,
+                answer,
+                fixture.line
+            )),
+            None => failures.push(format!(
+                "{}: no solver for {} day {} part {}",
+                fixture.name(),
+                year,
+                fixture.day,
+                part
+            )),
+        }
+    }
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let mut failures = Vec::new();
+        for &year in registry::YEARS {
+            let fixtures = load(year).unwrap_or_else(|e| panic!("{}", e));
+            for fixture in &fixtures {
+                let day = registry::validate(year, fixture.day);
+                assert_eq!(day, Ok(()), "{} (line {})", fixture.name(), fixture.line);
+                failures.extend(check(year, fixture));
+            }
+        }
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn test_parse() {
+        let manifest = "# comment\n\n[[example]]\nday = 8 # trailing\ninput = \"day08_test.txt\"\nparams.connections = 1_000\npart1 = 40\n\n[[example]]\nday = 1\ntext = \"R1000\\n\"\npart2 = \"10\"\n";
+        let fixtures = parse(manifest).unwrap();
+        assert_eq!(fixtures.len(), 2);
+        assert_eq!(fixtures[0].line, 3);
+        assert_eq!(
+            fixtures[0].source,
+            Source::File("day08_test.txt".to_string())
+        );
+        assert_eq!(fixtures[0].params["connections"], "1000");
+        assert_eq!(fixtures[0].expected[&1], Answer::from(40));
+        assert_eq!(fixtures[1].source, Source::Text("R1000\n".to_string()));
+        assert_eq!(fixtures[1].expected[&2], Answer::from(10));
+        assert!(!fixtures[1].expected.contains_key(&1));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse("day = 1\n"),
+            Err(ManifestError::OutsideExample { line: 1 })
+        );
+        assert_eq!(
+            parse("[[example]]\nday = 1\n"),
+            Err(ManifestError::MissingKey {
+                line: 1,
+                key: "input or text"
+            })
+        );
+        assert_eq!(
+            parse("[[example]]\nday = \"one\"\n"),
+            Err(ManifestError::InvalidValue {
+                line: 2,
+                key: "day".to_string()
+            })
+        );
+        assert_eq!(
+            parse("[[example]]\npart1 = 1\npart1 = 2\n"),
+            Err(ManifestError::DuplicateKey {
+                line: 3,
+                key: "part1".to_string()
+            })
+        );
+        assert_eq!(
+            parse("[[example]]\nanswer = 1\n"),
+            Err(ManifestError::UnknownKey {
+                line: 2,
+                key: "answer".to_string()
+            })
+        );
+        assert_eq!(
+            parse("[[example]]\ntext = \"unterminated\n"),
+            Err(ManifestError::InvalidValue {
+                line: 2,
+                key: "text".to_string()
+            })
+        );
+        assert_eq!(parse("[example]\n"), Err(ManifestError::Syntax { line: 1 }));
+    }
+}
@@ -0,0 +1,178 @@
+//! Random puzzle inputs for stress testing. Every generator is deterministic
+//! in its `Rng`, and `size` scales the input roughly linearly: lines for the
+//! line-based days, side length for the grids.
+
+use crate::rng::Rng;
+
+/// Days that have a generator.
+pub const DAYS: std::ops::RangeInclusive<u32> = 1..=8;
+
+pub fn generate(day: u32, size: usize, rng: &mut Rng) -> Option<String> {
+    let input = match day {
+        1 => day01(size, rng),
+        2 => day02(size, rng),
+        3 => day03(size, rng),
+        4 => day04(size, rng),
+        5 => day05(size, rng),
+        6 => day06(size, rng),
+        7 => day07(size, rng),
+        8 => day08(size, rng),
+        _ => return None,
+    };
+    Some(input)
+}
+
+/// Dial rotations such as `L68` or `R48`.
+pub fn day01(size: usize, rng: &mut Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let direction = rng.choose(&['L', 'R']);
+        out.push_str(&format!("{}{}\n", direction, rng.between(1, 999)));
+    }
+    out
+}
+
+/// One comma separated line of ID ranges `a-b` with up to ten digit IDs.
+/// Ranges get wider as `size` grows.
+pub fn day02(size: usize, rng: &mut Rng) -> String {
+    let ranges: Vec<String> = (0..size)
+        .map(|_| {
+            let digits = rng.between(1, 10) as u32;
+            let start = rng.between(10i64.pow(digits - 1), 10i64.pow(digits) - 1);
+            let end = start + rng.between(0, 10 * size as i64);
+            format!("{}-{}", start, end)
+        })
+        .collect();
+    format!("{}\n", ranges.join(","))
+}
+
+/// Banks of one hundred battery joltages `1..=9`.
+pub fn day03(size: usize, rng: &mut Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        for _ in 0..100 {
+            out.push(char::from(b'0' + rng.between(1, 9) as u8));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// A `size` by `size` grid of paper rolls `@` and empty floor `.`.
+pub fn day04(size: usize, rng: &mut Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            out.push(if rng.chance(0.65) { '@' } else { '.' });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// `size` fresh-ID ranges, a blank line, then `size` IDs to look up.
+pub fn day05(size: usize, rng: &mut Rng) -> String {
+    const MAX_ID: i64 = 1_000_000_000_000_000;
+    let mut out = String::new();
+    for _ in 0..size {
+        let start = rng.between(1, MAX_ID);
+        let end = (start + rng.between(0, MAX_ID / 1_000)).min(MAX_ID);
+        out.push_str(&format!("{}-{}\n", start, end));
+    }
+    out.push('\n');
+    for _ in 0..size {
+        out.push_str(&format!("{}\n", rng.between(1, MAX_ID)));
+    }
+    out
+}
+
+/// A worksheet of `size` problems with four rows of up to three digit
+/// numbers, each number aligned left or right within its problem.
+pub fn day06(size: usize, rng: &mut Rng) -> String {
+    const ROWS: usize = 4;
+    let mut lines = vec![String::new(); ROWS + 1];
+    for problem in 0..size {
+        let numbers: Vec<String> = (0..ROWS).map(|_| rng.between(1, 999).to_string()).collect();
+        let width = numbers.iter().map(String::len).max().unwrap();
+        let separator = if problem == 0 { "" } else { " " };
+
+        for (line, number) in lines.iter_mut().zip(&numbers) {
+            line.push_str(separator);
+            if rng.chance(0.5) {
+                line.push_str(&format!("{:<width$}", number));
+            } else {
+                line.push_str(&format!("{:>width$}", number));
+            }
+        }
+        let operator = rng.choose(&['+', '*']);
+        lines[ROWS].push_str(&format!("{}{:<width$}", separator, operator));
+    }
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+/// A manifold `size` rows tall with the start centred in the first row and
+/// splitters on every other row.
+pub fn day07(size: usize, rng: &mut Rng) -> String {
+    let width = size.max(1) | 1;
+    let mut out = String::new();
+    out.push_str(&".".repeat(width / 2));
+    out.push('S');
+    out.push_str(&".".repeat(width / 2));
+    out.push('\n');
+    for row in 1..size {
+        for _ in 0..width {
+            out.push(if row % 2 == 0 && rng.chance(0.3) {
+                '^'
+            } else {
+                '.'
+            });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// `size` junction boxes at random coordinates in a 100000 unit cube.
+pub fn day08(size: usize, rng: &mut Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let x = rng.between(0, 99_999);
+        let y = rng.between(0, 99_999);
+        let z = rng.between(0, 99_999);
+        out.push_str(&format!("{},{},{}\n", x, y, z));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reproducible() {
+        for day in DAYS {
+            let a = generate(day, 20, &mut Rng::new(42)).unwrap();
+            let b = generate(day, 20, &mut Rng::new(42)).unwrap();
+            let c = generate(day, 20, &mut Rng::new(43)).unwrap();
+            assert_eq!(a, b);
+            assert_ne!(a, c);
+        }
+        assert_eq!(generate(26, 20, &mut Rng::new(42)), None);
+    }
+
+    #[test]
+    fn test_shapes() {
+        let mut rng = Rng::new(1);
+        assert_eq!(day01(7, &mut rng).lines().count(), 7);
+        assert_eq!(day02(7, &mut rng).split(',').count(), 7);
+        assert!(day04(9, &mut rng).lines().all(|line| line.len() == 9));
+
+        let worksheet = day06(5, &mut rng);
+        let widths: Vec<usize> = worksheet.lines().map(str::len).collect();
+        assert!(widths.iter().all(|&w| w == widths[0]));
+
+        let manifold = day07(10, &mut rng);
+        assert_eq!(manifold.matches('S').count(), 1);
+        assert_eq!(manifold.lines().count(), 10);
+    }
+}
@@ -0,0 +1,398 @@
+//! Palette images and the PNG and GIF encoders that write them, so the grid
+//! visualizations can be shared as files without external tools. Encoding
+//! is fully deterministic: the same image always gives the same bytes.
+
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+/// An image whose pixels index into a palette of at most 256 colors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Indexed {
+    pub width: usize,
+    pub height: usize,
+    pub palette: Vec<Rgb>,
+    pub pixels: Vec<u8>,
+}
+
+/// Which color each cell state is drawn in. Cells not listed get the
+/// background, which is palette entry 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellColors {
+    pub background: Rgb,
+    pub cells: Vec<(char, Rgb)>,
+}
+
+impl CellColors {
+    pub fn palette(&self) -> Vec<Rgb> {
+        let mut palette = vec![self.background];
+        palette.extend(self.cells.iter().map(|&(_, color)| color));
+        palette
+    }
+
+    pub fn index(&self, c: char) -> u8 {
+        self.cells
+            .iter()
+            .position(|&(cell, _)| cell == c)
+            .map_or(0, |i| i as u8 + 1)
+    }
+}
+
+impl Indexed {
+    /// A `width` by `height` image filled with palette entry 0.
+    pub fn new(width: usize, height: usize, palette: Vec<Rgb>) -> Self {
+        assert!(
+            !palette.is_empty() && palette.len() <= 256,
+            "palette must have 1 to 256 colors"
+        );
+        Indexed {
+            width,
+            height,
+            palette,
+            pixels: vec![0; width * height],
+        }
+    }
+
+    /// Draws every cell of `grid` as a `scale` by `scale` square. Ragged rows
+    /// leave the rest of the line as background.
+    pub fn from_grid(grid: &[Vec<char>], scale: usize, colors: &CellColors) -> Self {
+        let width = grid.iter().map(Vec::len).max().unwrap_or(0);
+        let mut image = Indexed::new(width * scale, grid.len() * scale, colors.palette());
+        for (y, row) in grid.iter().enumerate() {
+            for (x, &c) in row.iter().enumerate() {
+                image.fill_rect(x * scale, y * scale, scale, scale, colors.index(c));
+            }
+        }
+        image
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, color: u8) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = color;
+        }
+    }
+
+    /// Fills a rectangle, clipped to the image.
+    pub fn fill_rect(&mut self, x: usize, y: usize, width: usize, height: usize, color: u8) {
+        for yy in y..(y + height).min(self.height) {
+            for xx in x..(x + width).min(self.width) {
+                self.pixels[yy * self.width + xx] = color;
+            }
+        }
+    }
+
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+
+        let mut header = Vec::new();
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // 8 bits per pixel, palette color, default compression, filter and
+        // no interlacing.
+        header.extend([8, 3, 0, 0, 0]);
+        png_chunk(&mut out, b"IHDR", &header);
+
+        let palette: Vec<u8> = self.palette.iter().flat_map(|c| [c.0, c.1, c.2]).collect();
+        png_chunk(&mut out, b"PLTE", &palette);
+
+        // Every scanline starts with filter type 0, none.
+        let mut raw = Vec::with_capacity((self.width + 1) * self.height);
+        for row in self.pixels.chunks(self.width.max(1)).take(self.height) {
+            raw.push(0);
+            raw.extend(row);
+        }
+        png_chunk(&mut out, b"IDAT", &zlib(&raw, self.width + 1));
+        png_chunk(&mut out, b"IEND", &[]);
+        out
+    }
+}
+
+/// Encodes `frames` as a looping GIF animation, showing each for `delay`
+/// hundredths of a second. All frames must share the first one's size and
+/// palette.
+pub fn gif(frames: &[Indexed], delay: u16) -> Vec<u8> {
+    let first = frames
+        .first()
+        .expect("an animation needs at least one frame");
+    assert!(
+        frames
+            .iter()
+            .all(|f| (f.width, f.height, &f.palette) == (first.width, first.height, &first.palette)),
+        "frames must share size and palette"
+    );
+
+    // The color table holds a power of two entries, at least 4 for LZW.
+    let bits = (first.palette.len().next_power_of_two().trailing_zeros() as u8).max(2);
+    let mut out = b"GIF89a".to_vec();
+    out.extend((first.width as u16).to_le_bytes());
+    out.extend((first.height as u16).to_le_bytes());
+    out.extend([0x80 | ((bits - 1) << 4) | (bits - 1), 0, 0]);
+    for i in 0..1 << bits {
+        let Rgb(r, g, b) = first.palette.get(i).copied().unwrap_or(Rgb(0, 0, 0));
+        out.extend([r, g, b]);
+    }
+
+    // Loop forever.
+    out.extend(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+    for frame in frames {
+        out.extend([0x21, 0xf9, 4, 0]);
+        out.extend(delay.to_le_bytes());
+        out.extend([0, 0]);
+
+        out.push(0x2c);
+        out.extend([0, 0, 0, 0]);
+        out.extend((frame.width as u16).to_le_bytes());
+        out.extend((frame.height as u16).to_le_bytes());
+        out.push(0);
+
+        out.push(bits);
+        for block in lzw(&frame.pixels, bits).chunks(255) {
+            out.push(block.len() as u8);
+            out.extend(block);
+        }
+        out.push(0);
+    }
+
+    out.push(0x3b);
+    out
+}
+
+fn png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+/// Writes bits least significant first, as both DEFLATE and GIF's LZW do.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    count: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, bits: u32) {
+        self.buffer |= value << self.count;
+        self.count += bits;
+        while self.count >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    /// Huffman codes go out most significant bit first.
+    fn write_code(&mut self, code: u32, bits: u32) {
+        self.write(code.reverse_bits() >> (32 - bits), bits);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+/// Writes a literal/length symbol with the fixed Huffman code.
+fn write_symbol(out: &mut BitWriter, symbol: u16) {
+    let symbol = symbol as u32;
+    match symbol {
+        0..=143 => out.write_code(0x30 + symbol, 8),
+        144..=255 => out.write_code(0x190 + symbol - 144, 9),
+        256..=279 => out.write_code(symbol - 256, 7),
+        _ => out.write_code(0xc0 + symbol - 280, 8),
+    }
+}
+
+/// The code for `value` in a base/extra-bits table, and its extra bits.
+fn code_for(value: u16, base: &[u16], extra: &[u8]) -> (usize, u32, u32) {
+    let code = base.iter().rposition(|&b| b <= value).unwrap();
+    (code, (value - base[code]) as u32, extra[code] as u32)
+}
+
+/// Compresses `data` into a zlib stream of one fixed-Huffman DEFLATE block.
+/// Rendered grids repeat along rows and between scanlines, so it only looks
+/// for matches one byte back and one `stride` back.
+fn zlib(data: &[u8], stride: usize) -> Vec<u8> {
+    let mut out = BitWriter::default();
+    out.write(1, 1);
+    out.write(1, 2);
+
+    let distances: Vec<usize> = [1, stride]
+        .into_iter()
+        .filter(|&d| (1..=32768).contains(&d))
+        .collect();
+    let mut i = 0;
+    while i < data.len() {
+        let (length, distance) = distances
+            .iter()
+            .filter(|&&d| d <= i)
+            .map(|&d| {
+                let length = (0..258.min(data.len() - i))
+                    .take_while(|&k| data[i + k] == data[i + k - d])
+                    .count();
+                (length, d)
+            })
+            .max_by_key(|&(length, d)| (length, std::cmp::Reverse(d)))
+            .unwrap_or((0, 0));
+
+        if length < 3 {
+            write_symbol(&mut out, data[i] as u16);
+            i += 1;
+            continue;
+        }
+        let (code, extra, bits) = code_for(length as u16, &LENGTH_BASE, &LENGTH_EXTRA);
+        write_symbol(&mut out, 257 + code as u16);
+        out.write(extra, bits);
+        let (code, extra, bits) = code_for(distance as u16, &DISTANCE_BASE, &DISTANCE_EXTRA);
+        out.write_code(code as u32, 5);
+        out.write(extra, bits);
+        i += length;
+    }
+    write_symbol(&mut out, 256);
+
+    let mut stream = vec![0x78, 0x01];
+    stream.extend(out.finish());
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+/// GIF's variable-width LZW, starting from `min_bits` bit codes.
+fn lzw(pixels: &[u8], min_bits: u8) -> Vec<u8> {
+    let clear = 1u16 << min_bits;
+    let end = clear + 1;
+    let mut out = BitWriter::default();
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut bits = min_bits as u32 + 1;
+    let mut next = end + 1;
+
+    out.write(clear as u32, bits);
+    let Some((&first, rest)) = pixels.split_first() else {
+        out.write(end as u32, bits);
+        return out.finish();
+    };
+    let mut prefix = first as u16;
+    for &pixel in rest {
+        if let Some(&code) = table.get(&(prefix, pixel)) {
+            prefix = code;
+            continue;
+        }
+        out.write(prefix as u32, bits);
+        if next == 4096 {
+            out.write(clear as u32, bits);
+            table.clear();
+            bits = min_bits as u32 + 1;
+            next = end + 1;
+        } else {
+            table.insert((prefix, pixel), next);
+            if next == 1 << bits {
+                bits += 1;
+            }
+            next += 1;
+        }
+        prefix = pixel as u16;
+    }
+    out.write(prefix as u32, bits);
+    // The decoder adds one more entry after that code, and may widen.
+    if next < 4096 && next == 1 << bits {
+        bits += 1;
+    }
+    out.write(end as u32, bits);
+    out.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_png_layout() {
+        let colors = CellColors {
+            background: Rgb(0, 0, 0),
+            cells: vec![('@', Rgb(0, 255, 0))],
+        };
+        let image = Indexed::from_grid(&[vec!['@', '.'], vec!['.']], 2, &colors);
+        assert_eq!((image.width, image.height), (4, 4));
+        assert_eq!(
+            image.pixels,
+            [1, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+
+        let png = image.to_png();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..29], [0, 0, 0, 4, 0, 0, 0, 4, 8, 3, 0, 0, 0]);
+        assert_eq!(&png[png.len() - 12..], b"\0\0\0\0IEND\xaeB`\x82");
+        assert_eq!(png, image.to_png());
+    }
+
+    #[test]
+    fn test_gif_layout() {
+        let palette = vec![Rgb(0, 0, 0), Rgb(255, 255, 255), Rgb(255, 0, 0)];
+        let mut frames = vec![Indexed::new(3, 2, palette)];
+        let mut second = frames[0].clone();
+        second.set(1, 1, 2);
+        frames.push(second);
+
+        let gif = gif(&frames, 10);
+        assert_eq!(&gif[..6], b"GIF89a");
+        assert_eq!(&gif[6..10], [3, 0, 2, 0]);
+        // Three colors pad to a four entry table.
+        assert_eq!(gif[10], 0x80 | 0x11);
+        assert_eq!(*gif.last().unwrap(), 0x3b);
+        assert_eq!(gif, super::gif(&frames, 10));
+    }
+}
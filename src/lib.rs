@@ -1,3 +1,25 @@
+pub mod answer;
+pub mod bigint;
+pub mod count;
+pub mod cycle;
+pub mod differential;
+pub mod explain;
+pub mod fixtures;
+pub mod generators;
+pub mod image;
+pub mod math;
+pub mod memo;
+pub mod online;
+pub mod prop;
+pub mod registry;
+pub mod repl;
+pub mod report;
+pub mod rng;
+pub mod stream;
+pub mod viz;
+pub mod watch;
+pub mod y2025;
+
 pub mod utils {
     // Placeholder for shared utilities
     pub fn read_lines(input: &str) -> Vec<&str> {
@@ -9,6 +31,411 @@ pub mod utils {
             .lines()
             .map(|line: &str| line.chars().collect())
             .collect();
-        return grid;
+        grid
+    }
+
+    /// FNV-1a, a small stable hash for telling inputs and outputs apart.
+    pub fn fnv1a(bytes: &[u8]) -> u64 {
+        let mut hash = Fnv1a::new();
+        hash.update(bytes);
+        hash.finish()
+    }
+
+    /// [`fnv1a`] fed a piece at a time, for inputs read as a stream.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Fnv1a(u64);
+
+    impl Fnv1a {
+        pub fn new() -> Self {
+            Fnv1a(0xcbf2_9ce4_8422_2325)
+        }
+
+        pub fn update(&mut self, bytes: &[u8]) {
+            for &b in bytes {
+                self.0 = (self.0 ^ b as u64).wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+
+        pub fn finish(&self) -> u64 {
+            self.0
+        }
+    }
+
+    impl Default for Fnv1a {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    /// Formats a grid back into the text `read_grid` parses.
+    pub struct GridDisplay<'a>(pub &'a [Vec<char>]);
+
+    impl std::fmt::Display for GridDisplay<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            for row in self.0 {
+                for c in row {
+                    write!(f, "{}", c)?;
+                }
+                writeln!(f)?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Transposes rows into columns: the `n`th item of the adapter holds the
+    /// `n`th item of every row. A [`Ragged`] policy decides what happens once
+    /// some rows run out before others.
+    pub struct Columns<I, P> {
+        rows: Vec<I>,
+        ragged: P,
+        column: usize,
+        done: bool,
+    }
+
+    impl<I: Iterator, P: Ragged<I::Item>> Columns<I, P> {
+        pub fn new<R>(rows: R, ragged: P) -> Self
+        where
+            R: IntoIterator,
+            R::Item: IntoIterator<IntoIter = I>,
+        {
+            Columns {
+                rows: rows.into_iter().map(IntoIterator::into_iter).collect(),
+                ragged,
+                column: 0,
+                done: false,
+            }
+        }
+
+        /// Groups of `size` consecutive columns, each column in all the
+        /// groups it's part of.
+        pub fn windows(self, size: usize) -> Windows<Self>
+        where
+            P::Column: Clone,
+        {
+            assert!(size > 0, "windows must hold at least one column");
+            Windows {
+                columns: self,
+                size,
+                window: std::collections::VecDeque::with_capacity(size),
+            }
+        }
+    }
+
+    impl<I: Iterator> Columns<I, Shortest> {
+        /// Columns up to the end of the shortest row.
+        pub fn shortest<R>(rows: R) -> Self
+        where
+            R: IntoIterator,
+            R::Item: IntoIterator<IntoIter = I>,
+        {
+            Columns::new(rows, Shortest)
+        }
+    }
+
+    impl<I: Iterator<Item: Clone>> Columns<I, Pad<I::Item>> {
+        /// Columns up to the end of the longest row, with `fill` standing in
+        /// for the rows that have ended.
+        pub fn padded<R>(rows: R, fill: I::Item) -> Self
+        where
+            R: IntoIterator,
+            R::Item: IntoIterator<IntoIter = I>,
+        {
+            Columns::new(rows, Pad(fill))
+        }
+    }
+
+    impl<I: Iterator> Columns<I, Strict> {
+        /// Columns of rows that must all be the same length.
+        pub fn strict<R>(rows: R) -> Self
+        where
+            R: IntoIterator,
+            R::Item: IntoIterator<IntoIter = I>,
+        {
+            Columns::new(rows, Strict)
+        }
+    }
+
+    impl<I: Iterator> Columns<I, Longest> {
+        /// Columns up to the end of the longest row, with `None` for the
+        /// rows that have ended, like a zip-longest.
+        pub fn longest<R>(rows: R) -> Self
+        where
+            R: IntoIterator,
+            R::Item: IntoIterator<IntoIter = I>,
+        {
+            Columns::new(rows, Longest)
+        }
+    }
+
+    impl<I: Iterator, P: Ragged<I::Item>> Iterator for Columns<I, P> {
+        type Item = P::Column;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.done || self.rows.is_empty() {
+                return None;
+            }
+            let cells: Vec<Option<I::Item>> = self.rows.iter_mut().map(Iterator::next).collect();
+            let column = match cells.iter().all(Option::is_none) {
+                true => None,
+                false => self.ragged.column(self.column, cells),
+            };
+            self.column += 1;
+            self.done = column
+                .as_ref()
+                .is_none_or(|column| self.ragged.is_last(column));
+            column
+        }
+    }
+
+    /// What [`Columns`] makes of a column, given the cell of every row, or
+    /// `None` where the row has already ended. Columns stop at the first
+    /// `None` returned, or once every row has ended.
+    pub trait Ragged<T> {
+        type Column;
+
+        fn column(&mut self, index: usize, cells: Vec<Option<T>>) -> Option<Self::Column>;
+
+        /// Whether no columns should follow `column`.
+        fn is_last(&self, _column: &Self::Column) -> bool {
+            false
+        }
+    }
+
+    /// Stop at the end of the shortest row, dropping the rest of the others.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Shortest;
+
+    impl<T> Ragged<T> for Shortest {
+        type Column = Vec<T>;
+
+        fn column(&mut self, _index: usize, cells: Vec<Option<T>>) -> Option<Vec<T>> {
+            cells.into_iter().collect()
+        }
+    }
+
+    /// Go on to the end of the longest row, filling in for the others.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Pad<T>(pub T);
+
+    impl<T: Clone> Ragged<T> for Pad<T> {
+        type Column = Vec<T>;
+
+        fn column(&mut self, _index: usize, cells: Vec<Option<T>>) -> Option<Vec<T>> {
+            Some(
+                cells
+                    .into_iter()
+                    .map(|cell| cell.unwrap_or_else(|| self.0.clone()))
+                    .collect(),
+            )
+        }
+    }
+
+    /// Fail at the first column where some rows have ended and others
+    /// haven't, then stop.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Strict;
+
+    impl<T> Ragged<T> for Strict {
+        type Column = Result<Vec<T>, RaggedError>;
+
+        fn column(
+            &mut self,
+            index: usize,
+            cells: Vec<Option<T>>,
+        ) -> Option<Result<Vec<T>, RaggedError>> {
+            match cells.iter().position(Option::is_none) {
+                Some(row) => Some(Err(RaggedError { row, column: index })),
+                None => Some(Ok(cells.into_iter().flatten().collect())),
+            }
+        }
+
+        fn is_last(&self, column: &Self::Column) -> bool {
+            column.is_err()
+        }
+    }
+
+    /// Go on to the end of the longest row, keeping `None` for the others.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Longest;
+
+    impl<T> Ragged<T> for Longest {
+        type Column = Vec<Option<T>>;
+
+        fn column(&mut self, _index: usize, cells: Vec<Option<T>>) -> Option<Vec<Option<T>>> {
+            Some(cells)
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct RaggedError {
+        /// The first row to end early.
+        pub row: usize,
+        pub column: usize,
+    }
+
+    impl std::fmt::Display for RaggedError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(
+                f,
+                "row {} ends at column {} but other rows go on",
+                self.row, self.column
+            )
+        }
+    }
+
+    impl std::error::Error for RaggedError {}
+
+    /// See [`Columns::windows`].
+    pub struct Windows<C: Iterator> {
+        columns: C,
+        size: usize,
+        window: std::collections::VecDeque<C::Item>,
+    }
+
+    impl<C: Iterator<Item: Clone>> Iterator for Windows<C> {
+        type Item = Vec<C::Item>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.window.len() == self.size {
+                self.window.pop_front();
+            }
+            while self.window.len() < self.size {
+                self.window.push_back(self.columns.next()?);
+            }
+            Some(self.window.iter().cloned().collect())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::prop::{for_all, shrink_vec, vec_of};
+
+        const ALPHABET: &[char] = &['.', '#', '@', '^', 'S', ' ', '7', 'é'];
+
+        #[test]
+        fn test_fnv1a() {
+            assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+            assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+            let mut hash = Fnv1a::new();
+            hash.update(b"ab");
+            hash.update(b"c");
+            assert_eq!(hash.finish(), fnv1a(b"abc"));
+        }
+
+        #[test]
+        fn test_read_lines_round_trip() {
+            for_all(|rng, size| {
+                vec_of(rng, size, |rng| {
+                    let len = rng.below(8) as usize;
+                    (0..len).map(|_| *rng.choose(ALPHABET)).collect::<String>()
+                })
+            })
+            .shrink_with(|lines| shrink_vec(lines, |_| Vec::new()))
+            .check(|lines| {
+                let text: String = lines.iter().map(|line| format!("{}\n", line)).collect();
+                read_lines(&text) == *lines
+            });
+        }
+
+        #[test]
+        fn test_read_grid_round_trip() {
+            for_all(|rng, size| {
+                let width = rng.below(size as u64 + 1) as usize;
+                vec_of(rng, size, |rng| {
+                    (0..width)
+                        .map(|_| *rng.choose(ALPHABET))
+                        .collect::<Vec<char>>()
+                })
+            })
+            .check(|grid| read_grid(&GridDisplay(grid).to_string()) == *grid);
+        }
+
+        #[test]
+        fn test_columns_shortest() {
+            let rows = [vec![1, 2, 3], vec![4, 5]];
+            let columns: Vec<Vec<i32>> = Columns::shortest(rows).collect();
+            assert_eq!(columns, [[1, 4], [2, 5]]);
+            assert_eq!(Columns::shortest(Vec::<Vec<u8>>::new()).count(), 0);
+        }
+
+        #[test]
+        fn test_columns_padded() {
+            let columns: Vec<String> = Columns::padded(["ab", "c", "def"].map(str::chars), ' ')
+                .map(String::from_iter)
+                .collect();
+            assert_eq!(columns, ["acd", "b e", "  f"]);
+        }
+
+        #[test]
+        fn test_columns_strict() {
+            let rows = [&[1, 2][..], &[3, 4]];
+            let columns: Result<Vec<Vec<&i32>>, RaggedError> = Columns::strict(rows).collect();
+            assert_eq!(columns, Ok(vec![vec![&1, &3], vec![&2, &4]]));
+
+            let rows = [vec![1, 2, 3], vec![4], vec![5, 6]];
+            let columns: Vec<_> = Columns::strict(rows).collect();
+            assert_eq!(
+                columns,
+                [Ok(vec![1, 4, 5]), Err(RaggedError { row: 1, column: 1 })]
+            );
+            assert_eq!(
+                columns[1].as_ref().unwrap_err().to_string(),
+                "row 1 ends at column 1 but other rows go on"
+            );
+        }
+
+        #[test]
+        fn test_columns_longest() {
+            let columns: Vec<Vec<Option<u8>>> =
+                Columns::longest([vec![1], vec![], vec![2, 3]]).collect();
+            assert_eq!(
+                columns,
+                [vec![Some(1), None, Some(2)], vec![None, None, Some(3)]]
+            );
+        }
+
+        #[test]
+        fn test_column_windows() {
+            let rows = ["abcd".chars(), "efgh".chars()];
+            let windows: Vec<String> = Columns::shortest(rows)
+                .windows(3)
+                .map(|window| window.into_iter().flatten().collect())
+                .collect();
+            assert_eq!(windows, ["aebfcg", "bfcgdh"]);
+            assert_eq!(Columns::shortest(["ab".chars()]).windows(3).count(), 0);
+        }
+
+        fn transpose(rows: &[Vec<u8>]) -> Vec<Vec<u8>> {
+            Columns::strict(rows.iter().map(|row| row.iter().copied()))
+                .collect::<Result<_, _>>()
+                .unwrap()
+        }
+
+        #[test]
+        fn test_columns_involution() {
+            for_all(|rng, size| {
+                let height = 1 + rng.below(size as u64 + 1) as usize;
+                let width = 1 + rng.below(size as u64 + 1) as usize;
+                (0..height)
+                    .map(|_| (0..width).map(|_| rng.below(10) as u8).collect())
+                    .collect::<Vec<Vec<u8>>>()
+            })
+            .shrink_with(|grid| {
+                let mut candidates = Vec::new();
+                if grid.len() > 1 {
+                    candidates.push(grid[1..].to_vec());
+                }
+                if grid[0].len() > 1 {
+                    candidates.push(grid.iter().map(|row| row[1..].to_vec()).collect());
+                }
+                candidates
+            })
+            .check(|grid| {
+                let transposed = transpose(grid);
+                transposed.len() == grid[0].len() && transpose(&transposed) == *grid
+            });
+            assert_eq!(transpose(&[]), Vec::<Vec<u8>>::new());
+        }
     }
 }
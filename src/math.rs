@@ -0,0 +1,396 @@
+//! Number theory that puzzles keep needing: gcd and lcm, modular arithmetic,
+//! the Chinese remainder theorem, integer square roots, primes and digits.
+//! Everything is generic over the primitive integer types through
+//! [`Integer`]. Signed arguments are fine wherever the result is defined, but
+//! moduli and bases must be positive.
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+pub trait Integer:
+    Copy
+    + Ord
+    + fmt::Debug
+    + fmt::Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_rem(self, other: Self) -> Option<Self>;
+
+    /// `self` modulo `m`, in `0..m` even for negative values.
+    fn rem_euclid(self, m: Self) -> Self;
+
+    /// `self * other % m` for `self` and `other` in `0..m`, without
+    /// overflowing. The default doubles and adds; types with a wider
+    /// primitive multiply in that instead.
+    fn mul_mod(self, other: Self, m: Self) -> Self {
+        let two = Self::ONE + Self::ONE;
+        let (mut a, mut b, mut product) = (self, other, Self::ZERO);
+        while b > Self::ZERO {
+            if b % two == Self::ONE {
+                product = add_mod(product, a, m);
+            }
+            a = add_mod(a, a, m);
+            b = b / two;
+        }
+        product
+    }
+}
+
+macro_rules! impl_integer {
+    ($t:ty $(, $wide:ty)?) => {
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+
+            fn checked_rem(self, other: Self) -> Option<Self> {
+                <$t>::checked_rem(self, other)
+            }
+
+            fn rem_euclid(self, m: Self) -> Self {
+                <$t>::rem_euclid(self, m)
+            }
+
+            $(
+                fn mul_mod(self, other: Self, m: Self) -> Self {
+                    (self as $wide * other as $wide % m as $wide) as $t
+                }
+            )?
+        }
+    };
+}
+
+impl_integer!(u8, u16);
+impl_integer!(u16, u32);
+impl_integer!(u32, u64);
+impl_integer!(u64, u128);
+impl_integer!(usize, u128);
+impl_integer!(u128);
+impl_integer!(i8, i16);
+impl_integer!(i16, i32);
+impl_integer!(i32, i64);
+impl_integer!(i64, i128);
+impl_integer!(isize, i128);
+impl_integer!(i128);
+
+/// `a + b` modulo `m`, for `a` and `b` in `0..m`, without overflowing.
+fn add_mod<T: Integer>(a: T, b: T, m: T) -> T {
+    if a >= m - b { a - (m - b) } else { a + b }
+}
+
+/// `a - b` modulo `m`, for `a` and `b` in `0..m`.
+fn sub_mod<T: Integer>(a: T, b: T, m: T) -> T {
+    if a >= b { a - b } else { a + (m - b) }
+}
+
+/// `|n|`, or `None` for a signed type's `MIN`.
+fn checked_abs<T: Integer>(n: T) -> Option<T> {
+    if n < T::ZERO {
+        T::ZERO.checked_sub(n)
+    } else {
+        Some(n)
+    }
+}
+
+/// The greatest common divisor, never negative. `gcd(0, 0)` is 0. Panics if
+/// the result doesn't fit, which only happens for `gcd(MIN, 0)` and
+/// `gcd(MIN, MIN)` of a signed type.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    // Euclid on the signed values, so that `MIN` is never negated. Only
+    // `MIN % -1` overflows, and its remainder is 0.
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        (a, b) = (b, a.checked_rem(b).unwrap_or(T::ZERO));
+    }
+    checked_abs(a).unwrap_or_else(|| panic!("gcd {} doesn't fit in its type", a))
+}
+
+/// The least common multiple, never negative, or `None` if it overflows.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    let (a, b) = (checked_abs(a)?, checked_abs(b)?);
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// `base` to the power `exp`, modulo `m`. Negative exponents aren't
+/// supported; see [`modinv`].
+pub fn modpow<T: Integer>(base: T, exp: T, m: T) -> T {
+    assert!(m > T::ZERO, "modulus must be positive, not {}", m);
+    assert!(exp >= T::ZERO, "exponent must not be negative, not {}", exp);
+    let two = T::ONE + T::ONE;
+    let (mut base, mut exp) = (base.rem_euclid(m), exp);
+    let mut result = T::ONE.rem_euclid(m);
+    while exp > T::ZERO {
+        if exp % two == T::ONE {
+            result = result.mul_mod(base, m);
+        }
+        base = base.mul_mod(base, m);
+        exp = exp / two;
+    }
+    result
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, or `None` if `a` and `m`
+/// share a factor.
+pub fn modinv<T: Integer>(a: T, m: T) -> Option<T> {
+    assert!(m > T::ZERO, "modulus must be positive, not {}", m);
+    // Extended Euclid, keeping the coefficient of `a` reduced modulo `m` so
+    // unsigned types work too.
+    let (mut r, mut next_r) = (m, a.rem_euclid(m));
+    let (mut t, mut next_t) = (T::ZERO, T::ONE.rem_euclid(m));
+    while next_r != T::ZERO {
+        let q = r / next_r;
+        (r, next_r) = (next_r, r - q * next_r);
+        (t, next_t) = (next_t, sub_mod(t, q.rem_euclid(m).mul_mod(next_t, m), m));
+    }
+    (r == T::ONE).then_some(t)
+}
+
+/// The smallest non-negative `x` with `x ≡ r (mod m)` for every
+/// `(r, m)` in `congruences`, and the modulus it repeats with. Moduli need
+/// not be coprime. `None` if the congruences contradict each other or the
+/// combined modulus overflows.
+pub fn crt<T: Integer>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    let mut x = T::ZERO;
+    let mut modulus = T::ONE;
+    for &(r, m) in congruences {
+        assert!(m > T::ZERO, "modulus must be positive, not {}", m);
+        // x + modulus * k ≡ r (mod m), solved for k modulo m / g.
+        let g = gcd(modulus, m);
+        let diff = sub_mod(r.rem_euclid(m), x.rem_euclid(m), m);
+        if diff % g != T::ZERO {
+            return None;
+        }
+        let reduced = m / g;
+        let inverse = modinv(modulus / g, reduced).expect("coprime once the gcd is divided out");
+        let k = (diff / g).rem_euclid(reduced).mul_mod(inverse, reduced);
+        let combined = (modulus / g).checked_mul(m)?;
+        x = add_mod(x, modulus.mul_mod(k, combined), combined);
+        modulus = combined;
+    }
+    Some((x, modulus))
+}
+
+/// The largest `r` with `r * r <= n`.
+pub fn isqrt<T: Integer>(n: T) -> T {
+    assert!(n >= T::ZERO, "no square root of {}", n);
+    let two = T::ONE + T::ONE;
+    if n < two {
+        return n;
+    }
+    // Newton's method from above, starting high enough that the first
+    // step can't overflow.
+    let mut x = n / two + T::ONE;
+    loop {
+        let y = (x + n / x) / two;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Whether each number up to and including `limit` is prime, by the sieve
+/// of Eratosthenes.
+pub fn sieve(limit: usize) -> Vec<bool> {
+    let mut is_prime = vec![true; limit + 1];
+    for n in is_prime.iter_mut().take(2) {
+        *n = false;
+    }
+    let mut p = 2;
+    while p * p <= limit {
+        if is_prime[p] {
+            for multiple in (p * p..=limit).step_by(p) {
+                is_prime[multiple] = false;
+            }
+        }
+        p += 1;
+    }
+    is_prime
+}
+
+/// The primes up to and including `limit`, in order.
+pub fn primes(limit: usize) -> Vec<usize> {
+    sieve(limit)
+        .into_iter()
+        .enumerate()
+        .filter_map(|(n, is_prime)| is_prime.then_some(n))
+        .collect()
+}
+
+/// Iterator over the digits of a number, most significant first. See
+/// [`digits`].
+#[derive(Debug, Clone)]
+pub struct Digits<T> {
+    /// What is left of the number once the digits so far are taken off.
+    n: T,
+    base: T,
+    /// Place value of the next digit, with the sign of `n` so that a signed
+    /// `MIN` never has to be negated; 0 once every digit is out.
+    place: T,
+}
+
+impl<T: Integer> Iterator for Digits<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.place == T::ZERO {
+            return None;
+        }
+        let digit = self.n / self.place;
+        self.n = self.n % self.place;
+        self.place = self.place / self.base;
+        Some(digit)
+    }
+}
+
+/// The digits of `n` in `base`, most significant first, without allocating.
+/// Zero has the single digit 0; the sign of a negative `n` is dropped.
+pub fn digits<T: Integer>(n: T, base: T) -> Digits<T> {
+    assert!(base > T::ONE, "base must be at least 2, not {}", base);
+    // Only a negative `n` makes `0 - 1` valid, so unsigned types never get
+    // there.
+    let mut place = if n < T::ZERO {
+        T::ZERO - T::ONE
+    } else {
+        T::ONE
+    };
+    while let Some(next) = place.checked_mul(base)
+        && n / next != T::ZERO
+    {
+        place = next;
+    }
+    Digits { n, base, place }
+}
+
+/// How many digits `n` has in `base`, as [`digits`] would yield.
+pub fn digit_count<T: Integer>(n: T, base: T) -> u32 {
+    assert!(base > T::ONE, "base must be at least 2, not {}", base);
+    let mut n = n;
+    let mut count = 1;
+    while n / base != T::ZERO {
+        n = n / base;
+        count += 1;
+    }
+    count
+}
+
+/// The number with `digits` in `base`, most significant first, or `None` if
+/// it overflows.
+pub fn from_digits<T: Integer>(digits: &[T], base: T) -> Option<T> {
+    digits
+        .iter()
+        .try_fold(T::ZERO, |n, &digit| n.checked_mul(base)?.checked_add(digit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12u32, 18), 6);
+        assert_eq!(gcd(-12i64, 18), 6);
+        assert_eq!(gcd(0u8, 0), 0);
+        assert_eq!(lcm(4i32, -6), Some(12));
+        assert_eq!(lcm(0u64, 5), Some(0));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(gcd(i64::MIN, 6), 2);
+        assert_eq!(gcd(-1, i64::MIN), 1);
+        assert_eq!(gcd(i8::MIN, 64), 64);
+        assert_eq!(lcm(i64::MIN, 1), None);
+        assert_eq!(lcm(i8::MIN / 2, -2), Some(64));
+    }
+
+    #[test]
+    fn test_modular() {
+        assert_eq!(modpow(2u64, 10, 1000), 24);
+        assert_eq!(modpow(-2i32, 3, 5), 2);
+        assert_eq!(modpow(7u8, 0, 1), 0);
+        // Fermat's little theorem on a prime too big to square in a u128.
+        let p = (1u128 << 127) - 1;
+        assert_eq!(modpow(3, p - 1, p), 1);
+        assert_eq!(modpow(u8::MAX - 1, 2, u8::MAX), 1);
+
+        assert_eq!(modinv(3u32, 11), Some(4));
+        assert_eq!(modinv(-3i64, 11), Some(7));
+        assert_eq!(modinv(6u16, 9), None);
+        assert_eq!(modinv(5u8, 1), Some(0));
+        let inverse = modinv(12345u128, p).unwrap();
+        assert_eq!(12345u128.mul_mod(inverse, p), 1);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2u64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1i32, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1u8, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(-1i64, 7)]), Some((6, 7)));
+        assert_eq!(crt::<u32>(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(0u8, 16), (0, 17)]), None);
+    }
+
+    #[test]
+    fn test_isqrt() {
+        for n in 0u32..2000 {
+            let r = isqrt(n);
+            assert!(r * r <= n && (r + 1) * (r + 1) > n, "isqrt({})", n);
+        }
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+        assert_eq!(isqrt(i128::MAX), 13043817825332782212);
+    }
+
+    #[test]
+    fn test_primes() {
+        assert_eq!(primes(30), [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert_eq!(primes(1), []);
+        assert_eq!(primes(100_000).len(), 9592);
+        assert!(!sieve(0)[0]);
+    }
+
+    #[test]
+    fn test_digits() {
+        let collect = |n: i64, base: i64| digits(n, base).collect::<Vec<_>>();
+        assert_eq!(digits(1203u32, 10).collect::<Vec<_>>(), [1, 2, 0, 3]);
+        assert_eq!(collect(0, 10), [0]);
+        assert_eq!(collect(-10, 2), [1, 0, 1, 0]);
+        assert_eq!(
+            collect(i64::MIN, 10),
+            [9, 2, 2, 3, 3, 7, 2, 0, 3, 6, 8, 5, 4, 7, 7, 5, 8, 0, 8]
+        );
+        assert_eq!(collect(i64::MIN, 2).len(), 64);
+        assert_eq!(digits(u8::MAX, 16).collect::<Vec<_>>(), [15, 15]);
+        assert_eq!(digit_count(u64::MAX, 10), 20);
+        assert_eq!(digit_count(0u8, 10), 1);
+        assert_eq!(digit_count(i64::MIN, 10), 19);
+        for n in [0u64, 7, 10, 16, 999, u64::MAX] {
+            let digits: Vec<u64> = digits(n, 16).collect();
+            assert_eq!(from_digits(&digits, 16), Some(n));
+            assert_eq!(digit_count(n, 16) as usize, digits.len());
+        }
+        assert_eq!(from_digits(&[2u8, 5, 6], 10), None);
+    }
+}
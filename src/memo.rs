@@ -0,0 +1,200 @@
+//! Memoized recursion: a [`Memo`] caches a recursive function's results by
+//! argument, and the function recurses through it instead of calling itself,
+//! so closures that borrow the puzzle can be memoized too. A memo can be
+//! bounded, evicting the least recently used entries, and counts its hits
+//! and misses.
+//!
+//! Every nested lookup is a call on the stack, so a memo suits recursion a
+//! few thousand levels deep at most. Anything deeper, or as deep as an
+//! untrusted input makes it, wants an iterative fold instead.
+//!
+//! ```
+//! use advent_of_code::memo::Memo;
+//!
+//! let mut memo = Memo::new();
+//! let fib = |fib: &mut dyn FnMut(u64) -> u64, n: u64| {
+//!     if n < 2 { n } else { fib(n - 1) + fib(n - 2) }
+//! };
+//! assert_eq!(memo.get(90, &fib), 2880067194370816120);
+//! assert_eq!(memo.stats().misses, 91);
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Entries dropped to stay within the capacity.
+    pub evictions: u64,
+}
+
+impl Stats {
+    /// Fraction of lookups answered from the cache.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+pub struct Memo<K, V> {
+    /// Every cached value with the tick it was last used at.
+    entries: HashMap<K, (V, u64)>,
+    /// Keys by the tick they were last used at, oldest first, for eviction.
+    /// Only kept when the memo is bounded.
+    recency: BTreeMap<u64, K>,
+    capacity: Option<usize>,
+    tick: u64,
+    stats: Stats,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    /// A memo that keeps every result.
+    pub fn new() -> Self {
+        Memo {
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            capacity: None,
+            tick: 0,
+            stats: Stats::default(),
+        }
+    }
+
+    /// A memo that keeps at most `capacity` results, evicting the least
+    /// recently used.
+    pub fn lru(capacity: usize) -> Self {
+        Memo {
+            capacity: Some(capacity.max(1)),
+            ..Memo::new()
+        }
+    }
+
+    /// The value for `key`, computed by `f` on a miss. `f` gets a function
+    /// to look up other keys through this memo, and must use it instead of
+    /// recursing directly for those results to be cached.
+    pub fn get<F>(&mut self, key: K, f: &F) -> V
+    where
+        F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+    {
+        if let Some(value) = self.lookup(&key) {
+            self.stats.hits += 1;
+            return value;
+        }
+        self.stats.misses += 1;
+        let value = f(&mut |k| self.get(k, f), key.clone());
+        self.insert(key, value.clone());
+        value
+    }
+
+    fn lookup(&mut self, key: &K) -> Option<V> {
+        self.tick += 1;
+        let (value, used) = self.entries.get_mut(key)?;
+        if self.capacity.is_some() {
+            let key = self.recency.remove(used).unwrap();
+            self.recency.insert(self.tick, key);
+        }
+        *used = self.tick;
+        Some(value.clone())
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        self.tick += 1;
+        if let Some(capacity) = self.capacity {
+            if let Some((_, used)) = self.entries.get(&key) {
+                self.recency.remove(used);
+            } else if self.entries.len() == capacity
+                && let Some((_, oldest)) = self.recency.pop_first()
+            {
+                self.entries.remove(&oldest);
+                self.stats.evictions += 1;
+            }
+            self.recency.insert(self.tick, key.clone());
+        }
+        self.entries.insert(key, (value, self.tick));
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    /// Drops every cached result and resets the statistics.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+        self.stats = Stats::default();
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Number of lattice paths from `(x, y)` to the origin.
+    fn paths(paths: &mut dyn FnMut((u64, u64)) -> u64, (x, y): (u64, u64)) -> u64 {
+        match (x, y) {
+            (0, _) | (_, 0) => 1,
+            _ => paths((x - 1, y)) + paths((x, y - 1)),
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut memo = Memo::new();
+        assert_eq!(memo.get((16, 16), &paths), 601080390);
+        // Every cell of the 17 by 17 grid but the origin is computed once,
+        // and each of the 16 * 16 inner cells asks for two others.
+        let stats = memo.stats();
+        assert_eq!(stats.misses, 17 * 17 - 1);
+        assert_eq!(stats.hits, 1 + 2 * 16 * 16 - stats.misses);
+        assert_eq!(memo.len(), 17 * 17 - 1);
+
+        assert_eq!(memo.get((16, 16), &paths), 601080390);
+        assert_eq!(memo.stats().hits, stats.hits + 1);
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(memo.stats(), Stats::default());
+    }
+
+    #[test]
+    fn test_lru() {
+        let mut memo = Memo::lru(2);
+        let square = |_: &mut dyn FnMut(u32) -> u32, n: u32| n * n;
+        memo.get(1, &square);
+        memo.get(2, &square);
+        memo.get(1, &square);
+        memo.get(3, &square); // evicts 2, the least recently used
+        assert_eq!(memo.len(), 2);
+        assert_eq!(memo.stats().evictions, 1);
+
+        let misses = memo.stats().misses;
+        memo.get(1, &square);
+        assert_eq!(memo.stats().misses, misses);
+        memo.get(2, &square);
+        assert_eq!(memo.stats().misses, misses + 1);
+    }
+
+    #[test]
+    fn test_bounded_recursion_is_still_correct() {
+        let mut memo = Memo::lru(4);
+        assert_eq!(memo.get((10, 10), &paths), 184756);
+        assert!(memo.len() <= 4);
+        assert!(memo.stats().evictions > 0);
+    }
+}
@@ -0,0 +1,35 @@
+//! Solvers that take their input a line at a time and can report the answer
+//! for the lines seen so far, for exploring a puzzle interactively.
+
+use crate::registry::Answer;
+
+pub trait Online {
+    /// Feeds the next line, without its newline. A line that can't be part of
+    /// the input is rejected and leaves the state unchanged.
+    fn push_line(&mut self, line: &str) -> Result<(), String>;
+
+    /// The answer to `part` for the lines pushed so far, or `None` if the
+    /// part has no answer yet.
+    fn current_answer(&self, part: u32) -> Option<Answer>;
+
+    /// Forgets every line pushed so far.
+    fn reset(&mut self);
+}
+
+/// Pushes every line of `input`, stopping at the first rejected one.
+pub fn feed(solver: &mut dyn Online, input: &str) -> Result<(), String> {
+    for (i, line) in input.lines().enumerate() {
+        solver
+            .push_line(line)
+            .map_err(|e| format!("line {}: {}", i + 1, e))?;
+    }
+    Ok(())
+}
+
+/// Both answers after feeding all of `input`, as text, for comparing online
+/// solvers with the whole-input ones.
+pub fn answers(solver: &mut dyn Online, input: &str) -> Result<[Option<String>; 2], String> {
+    solver.reset();
+    feed(solver, input)?;
+    Ok([1, 2].map(|part| solver.current_answer(part).map(|a| a.to_string())))
+}
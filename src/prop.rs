@@ -0,0 +1,162 @@
+//! Minimal property testing: generate values from a seeded `Rng`, check a
+//! property on each, and greedily shrink the first counterexample.
+
+use crate::rng::Rng;
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+
+type Shrinker<T> = Box<dyn Fn(&T) -> Vec<T>>;
+
+pub struct Property<T, G> {
+    generate: G,
+    shrink: Shrinker<T>,
+    cases: u64,
+    max_size: usize,
+    seed: u64,
+}
+
+/// Starts a property over values produced by `generate(rng, size)`.
+pub fn for_all<T, G>(generate: G) -> Property<T, G>
+where
+    T: Clone + Debug,
+    G: Fn(&mut Rng, usize) -> T,
+{
+    Property {
+        generate,
+        shrink: Box::new(|_| Vec::new()),
+        cases: 256,
+        max_size: 32,
+        seed: 0,
+    }
+}
+
+impl<T, G> Property<T, G>
+where
+    T: Clone + Debug,
+    G: Fn(&mut Rng, usize) -> T,
+{
+    /// Candidates simpler than a value, tried in order while shrinking.
+    pub fn shrink_with(mut self, shrink: impl Fn(&T) -> Vec<T> + 'static) -> Self {
+        self.shrink = Box::new(shrink);
+        self
+    }
+
+    pub fn cases(mut self, cases: u64) -> Self {
+        self.cases = cases;
+        self
+    }
+
+    pub fn max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Panics with the shrunk counterexample if `property` returns false or
+    /// panics for any generated value.
+    pub fn check(self, property: impl Fn(&T) -> bool) {
+        let holds =
+            |value: &T| panic::catch_unwind(AssertUnwindSafe(|| property(value))).unwrap_or(false);
+
+        for case in 0..self.cases {
+            let size = case as usize % (self.max_size + 1);
+            let mut rng = Rng::new(self.seed.wrapping_add(case));
+            let value = (self.generate)(&mut rng, size);
+            if holds(&value) {
+                continue;
+            }
+
+            let mut minimal = value;
+            let mut steps = 0;
+            while let Some(simpler) = (self.shrink)(&minimal)
+                .into_iter()
+                .find(|candidate| !holds(candidate))
+            {
+                minimal = simpler;
+                steps += 1;
+            }
+            panic!(
+                "property failed for seed {} (shrunk {} times): {:?}",
+                self.seed.wrapping_add(case),
+                steps,
+                minimal
+            );
+        }
+    }
+}
+
+pub fn vec_of<T>(rng: &mut Rng, size: usize, mut element: impl FnMut(&mut Rng) -> T) -> Vec<T> {
+    let len = rng.below(size as u64 + 1) as usize;
+    (0..len).map(|_| element(rng)).collect()
+}
+
+/// Moves towards zero: zero itself, then halving, then a single step.
+pub fn shrink_i64(n: i64) -> Vec<i64> {
+    let mut candidates = Vec::new();
+    if n != 0 {
+        candidates.push(0);
+    }
+    if n / 2 != 0 {
+        candidates.push(n / 2);
+    }
+    if n.unsigned_abs() > 1 {
+        candidates.push(n - n.signum());
+    }
+    candidates
+}
+
+/// Drops the back half, then single elements, then shrinks elements in place.
+pub fn shrink_vec<T: Clone>(v: &[T], element: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut candidates = Vec::new();
+    if v.len() > 1 {
+        candidates.push(v[..v.len() / 2].to_vec());
+    }
+    for i in 0..v.len() {
+        let mut smaller = v.to_vec();
+        smaller.remove(i);
+        candidates.push(smaller);
+    }
+    for (i, item) in v.iter().enumerate() {
+        for simpler in element(item) {
+            let mut shrunk = v.to_vec();
+            shrunk[i] = simpler;
+            candidates.push(shrunk);
+        }
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shrinks_to_minimal_counterexample() {
+        let result = panic::catch_unwind(|| {
+            for_all(|rng, size| vec_of(rng, size, |rng| rng.between(-100, 100)))
+                .shrink_with(|v| shrink_vec(v, |&n| shrink_i64(n)))
+                .check(|v| v.iter().all(|&n| n < 10));
+        });
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.ends_with(": [10]"), "{}", message);
+    }
+
+    #[test]
+    fn test_shrink_i64() {
+        assert_eq!(shrink_i64(0), Vec::<i64>::new());
+        assert_eq!(shrink_i64(1), [0]);
+        assert_eq!(shrink_i64(-10), [0, -5, -9]);
+        assert_eq!(shrink_i64(i64::MAX), [0, i64::MAX / 2, i64::MAX - 1]);
+        assert_eq!(shrink_i64(i64::MIN), [0, i64::MIN / 2, i64::MIN + 1]);
+    }
+
+    #[test]
+    fn test_passing_property() {
+        for_all(|rng, size| vec_of(rng, size, |rng| rng.between(0, 9)))
+            .check(|v| v.iter().sum::<i64>() <= 9 * v.len() as i64);
+    }
+}
@@ -0,0 +1,215 @@
+//! Every year's solvers, looked up by `(year, day)`, and the types they
+//! share. Each year lives in its own `yYYYY` module with a `solve` function
+//! for its days.
+
+pub use crate::answer::Answer;
+use crate::explain::Trace;
+use crate::fixtures::inputs_dir;
+use crate::online::Online;
+use crate::repl::Session;
+use crate::rng::Rng;
+use crate::viz::{Frame, Palette};
+use crate::y2025;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Years with solvers, oldest first.
+pub const YEARS: &[u32] = &[2025];
+
+/// The year commands use unless told otherwise.
+pub const DEFAULT_YEAR: u32 = 2025;
+
+/// The days a year's event ran for: 25 from 2015 to 2024, and 12 since 2025.
+pub fn event_days(year: u32) -> Option<RangeInclusive<u32>> {
+    match year {
+        2015..=2024 => Some(1..=25),
+        2025.. => Some(1..=12),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DayError {
+    NoEvent { year: u32 },
+    OutOfRange { year: u32, day: u32 },
+}
+
+impl fmt::Display for DayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DayError::NoEvent { year } => write!(f, "there was no Advent of Code in {}", year),
+            DayError::OutOfRange { year, day } => {
+                let days = event_days(*year).unwrap();
+                write!(
+                    f,
+                    "{} only has days {} to {}, not {}",
+                    year,
+                    days.start(),
+                    days.end(),
+                    day
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for DayError {}
+
+/// Checks that `day` was part of `year`'s event.
+pub fn validate(year: u32, day: u32) -> Result<(), DayError> {
+    let days = event_days(year).ok_or(DayError::NoEvent { year })?;
+    if !days.contains(&day) {
+        return Err(DayError::OutOfRange { year, day });
+    }
+    Ok(())
+}
+
+/// Where a year's inputs and examples live: `inputs/<year>/`.
+pub fn year_dir(year: u32) -> PathBuf {
+    inputs_dir().join(year.to_string())
+}
+
+pub fn input_path(year: u32, day: u32) -> PathBuf {
+    year_dir(year).join(format!("day{:02}.txt", day))
+}
+
+/// Named knobs a day's solver takes besides its input, such as how many
+/// connections 2025's day 8 makes. Values are kept as the text they were
+/// given in.
+pub type Params = BTreeMap<String, String>;
+
+pub fn param<T: FromStr>(params: &Params, key: &str, default: T) -> T {
+    match params.get(key) {
+        Some(value) => value
+            .parse()
+            .unwrap_or_else(|_| panic!("invalid value {:?} for {}", value, key)),
+        None => default,
+    }
+}
+
+/// Solves one part of one day, or returns `None` if there is no solver for
+/// it.
+pub fn solve(year: u32, day: u32, part: u32, input: &str, params: &Params) -> Option<Answer> {
+    match year {
+        2025 => y2025::solve(day, part, input, params),
+        _ => None,
+    }
+}
+
+/// Solves one part of one day while noting how on `trace`, for days that
+/// can explain their answers.
+pub fn explain(
+    year: u32,
+    day: u32,
+    part: u32,
+    input: &str,
+    params: &Params,
+    trace: &mut Trace,
+) -> Option<Answer> {
+    match year {
+        2025 => y2025::explain(day, part, input, params, trace),
+        _ => None,
+    }
+}
+
+/// Solves one part of one day from a stream, for days that can read their
+/// input without holding all of it. `None` if the day can't.
+pub fn solve_stream(
+    year: u32,
+    day: u32,
+    part: u32,
+    reader: &mut dyn BufRead,
+    params: &Params,
+) -> Option<io::Result<Answer>> {
+    match year {
+        2025 => y2025::solve_stream(day, part, reader, params),
+        _ => None,
+    }
+}
+
+/// A fresh line-at-a-time solver, for days that have one.
+pub fn online(year: u32, day: u32) -> Option<Box<dyn Online>> {
+    match year {
+        2025 => y2025::online(day),
+        _ => None,
+    }
+}
+
+/// A command session over `input` for `aoc repl`, for days that have one.
+pub fn repl(year: u32, day: u32, input: &str) -> Option<Box<dyn Session>> {
+    match year {
+        2025 => y2025::repl(day, input),
+        _ => None,
+    }
+}
+
+/// Frames and colors for the days that have a visualization.
+pub fn visualize(year: u32, day: u32, input: &str) -> Option<(Vec<Frame>, Palette)> {
+    match year {
+        2025 => y2025::visualize(day, input),
+        _ => None,
+    }
+}
+
+/// An image of the day's solution, as its file extension and bytes.
+pub fn image(year: u32, day: u32, input: &str, params: &Params) -> Option<(&'static str, Vec<u8>)> {
+    match year {
+        2025 => y2025::image(day, input, params),
+        _ => None,
+    }
+}
+
+/// An SVG drawing of the day's solution, for days that have one.
+pub fn svg(year: u32, day: u32, input: &str, params: &Params) -> Option<String> {
+    match year {
+        2025 => y2025::svg(day, input, params),
+        _ => None,
+    }
+}
+
+/// A random input of about `size` for stress testing, for days that have a
+/// generator.
+pub fn generate(year: u32, day: u32, size: usize, rng: &mut Rng) -> Option<String> {
+    match year {
+        2025 => y2025::generate(day, size, rng),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_length() {
+        assert_eq!(validate(2025, 12), Ok(()));
+        assert_eq!(
+            validate(2025, 13),
+            Err(DayError::OutOfRange {
+                year: 2025,
+                day: 13
+            })
+        );
+        assert_eq!(validate(2024, 25), Ok(()));
+        assert_eq!(validate(2014, 1), Err(DayError::NoEvent { year: 2014 }));
+        assert_eq!(
+            validate(2025, 0).unwrap_err().to_string(),
+            "2025 only has days 1 to 12, not 0"
+        );
+    }
+
+    #[test]
+    fn test_solvers_fit_their_event() {
+        for &year in YEARS {
+            let days = event_days(year).unwrap();
+            for day in (0..=25).filter(|day| !days.contains(day)) {
+                let solved = solve(year, day, 1, "", &Params::new()).is_some();
+                assert!(!solved, "{} has no day {}", year, day);
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! Small per-day command languages for poking at a parsed input from
+//! `aoc repl`, such as asking which circuit a junction box ends up in.
+
+use std::str::FromStr;
+
+pub trait Session {
+    /// Every command as its usage and what it does, for `help`.
+    fn commands(&self) -> &'static [(&'static str, &'static str)];
+
+    /// Runs `command` with its arguments and returns what to print.
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String>;
+}
+
+/// Runs one line typed at the prompt. `help` lists the session's commands;
+/// blank lines do nothing.
+pub fn execute(session: &mut dyn Session, line: &str) -> Result<String, String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let Some((&command, args)) = words.split_first() else {
+        return Ok(String::new());
+    };
+    if command == "help" {
+        let builtin = [("help", "list these commands"), ("quit", "leave")];
+        let commands: Vec<&(&str, &str)> = session.commands().iter().chain(&builtin).collect();
+        let width = commands
+            .iter()
+            .map(|(usage, _)| usage.len())
+            .max()
+            .unwrap_or(0);
+        let lines: Vec<String> = commands
+            .iter()
+            .map(|(usage, what)| format!("{:width$}  {}", usage, what))
+            .collect();
+        return Ok(lines.join("\n"));
+    }
+    if !session
+        .commands()
+        .iter()
+        .any(|(usage, _)| usage.split(' ').next() == Some(command))
+    {
+        return Err(format!("unknown command {:?}, try help", command));
+    }
+    session.run(command, args)
+}
+
+/// The argument at `index`, parsed, with a message naming it if it's
+/// missing or malformed.
+pub fn arg<T: FromStr>(args: &[&str], index: usize, name: &str) -> Result<T, String> {
+    let value = args.get(index).ok_or(format!("missing {}", name))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid {} {:?}", name, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Counter(i64);
+
+    impl Session for Counter {
+        fn commands(&self) -> &'static [(&'static str, &'static str)] {
+            &[("add N", "add N to the counter")]
+        }
+
+        fn run(&mut self, _command: &str, args: &[&str]) -> Result<String, String> {
+            self.0 += arg::<i64>(args, 0, "N")?;
+            Ok(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn test_execute() {
+        let mut counter = Counter(0);
+        assert_eq!(execute(&mut counter, "  add 2 "), Ok("2".to_string()));
+        assert_eq!(execute(&mut counter, ""), Ok(String::new()));
+        assert_eq!(execute(&mut counter, "add"), Err("missing N".to_string()));
+        assert_eq!(
+            execute(&mut counter, "add two"),
+            Err("invalid N \"two\"".to_string())
+        );
+        assert!(execute(&mut counter, "sub 1").is_err());
+        assert!(
+            execute(&mut counter, "help")
+                .unwrap()
+                .starts_with("add N  add N to")
+        );
+    }
+}
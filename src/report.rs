@@ -0,0 +1,398 @@
+//! Records of solver runs, as printed by `aoc run`, `check` and `bench` in
+//! JSON, CSV or an aligned table. Records carry the input's hash and the
+//! crate version so results can be compared across machines and commits.
+
+use crate::answer::Answer;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u32,
+    pub part: u32,
+    pub answer: Answer,
+    /// The expected answer, for checks.
+    pub expected: Option<Answer>,
+    /// Where the input came from: a path or an example's name.
+    pub input: String,
+    pub input_hash: u64,
+    /// How long every run took.
+    pub timings: Vec<Duration>,
+}
+
+impl Record {
+    /// Whether the answer was the expected one, if one was given.
+    pub fn passed(&self) -> Option<bool> {
+        self.expected
+            .as_ref()
+            .map(|expected| *expected == self.answer)
+    }
+
+    pub fn min(&self) -> Duration {
+        self.timings.iter().min().copied().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        let mut sorted = self.timings.clone();
+        sorted.sort();
+        sorted.get(sorted.len() / 2).copied().unwrap_or_default()
+    }
+
+    pub fn mean(&self) -> Duration {
+        match self.timings.len() {
+            0 => Duration::ZERO,
+            n => self.timings.iter().sum::<Duration>() / n as u32,
+        }
+    }
+
+    /// Every field as text, in the order of [`FIELDS`].
+    fn fields(&self) -> [String; 13] {
+        [
+            self.day.to_string(),
+            self.part.to_string(),
+            self.answer.to_string(),
+            self.answer.type_name().to_string(),
+            self.expected
+                .as_ref()
+                .map(Answer::to_string)
+                .unwrap_or_default(),
+            self.passed().map_or(String::new(), |p| p.to_string()),
+            self.input.clone(),
+            format!("{:016x}", self.input_hash),
+            self.timings.len().to_string(),
+            self.min().as_nanos().to_string(),
+            self.median().as_nanos().to_string(),
+            self.mean().as_nanos().to_string(),
+            VERSION.to_string(),
+        ]
+    }
+}
+
+const FIELDS: [&str; 13] = [
+    "day",
+    "part",
+    "answer",
+    "answer_type",
+    "expected",
+    "passed",
+    "input",
+    "input_hash",
+    "runs",
+    "min_ns",
+    "median_ns",
+    "mean_ns",
+    "version",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Csv,
+    Table,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "table" => Ok(Format::Table),
+            _ => Err(format!(
+                "unknown format {:?}, expected json, csv or table",
+                s
+            )),
+        }
+    }
+}
+
+/// Durations the way people read them: `850ns`, `12.3µs`, `4.56ms`, `1.20s`.
+pub struct Human(pub Duration);
+
+impl fmt::Display for Human {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.0.as_nanos();
+        let text = if nanos < 1_000 {
+            format!("{}ns", nanos)
+        } else if nanos < 1_000_000 {
+            format!("{:.1}µs", nanos as f64 / 1e3)
+        } else if nanos < 1_000_000_000 {
+            format!("{:.2}ms", nanos as f64 / 1e6)
+        } else {
+            format!("{:.2}s", nanos as f64 / 1e9)
+        };
+        f.pad(&text)
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// A JSON array with one object per record. Numbers that fit are numbers;
+/// answers stay strings so big ones survive any JSON parser.
+fn write_json(records: &[Record], out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "[")?;
+    for (i, record) in records.iter().enumerate() {
+        let values = record.fields();
+        let members: Vec<String> = FIELDS
+            .iter()
+            .zip(&values)
+            .map(|(&name, value)| {
+                let value = match name {
+                    "day" | "part" | "runs" | "min_ns" | "median_ns" | "mean_ns" => value.clone(),
+                    "expected" if record.expected.is_none() => "null".to_string(),
+                    "passed" => record
+                        .passed()
+                        .map_or("null".to_string(), |p| p.to_string()),
+                    _ => json_string(value),
+                };
+                format!("{}: {}", json_string(name), value)
+            })
+            .collect();
+        let comma = if i + 1 < records.len() { "," } else { "" };
+        writeln!(out, "  {{{}}}{}", members.join(", "), comma)?;
+    }
+    writeln!(out, "]")
+}
+
+/// Splits one line of CSV as written by [`write`], undoing the quoting.
+fn split_csv(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            c => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+/// Reads back CSV records as field name to value maps, for tools that run
+/// the runner and look at its results.
+pub fn read_csv(text: &str) -> Vec<BTreeMap<String, String>> {
+    let mut lines = text.lines();
+    let Some(header) = lines.next() else {
+        return Vec::new();
+    };
+    let names = split_csv(header);
+    lines
+        .map(|line| names.iter().cloned().zip(split_csv(line)).collect())
+        .collect()
+}
+
+fn write_csv(records: &[Record], out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "{}", FIELDS.join(","))?;
+    for record in records {
+        let fields: Vec<String> = record.fields().iter().map(|f| csv_field(f)).collect();
+        writeln!(out, "{}", fields.join(","))?;
+    }
+    Ok(())
+}
+
+/// The columns that matter at a glance; checks add the expected answer and
+/// benchmarks the spread of timings.
+fn write_table(records: &[Record], out: &mut impl Write) -> io::Result<()> {
+    let checks = records.iter().any(|r| r.expected.is_some());
+    let benchmarks = records.iter().any(|r| r.timings.len() > 1);
+
+    let mut header = vec!["day", "part", "answer", "type"];
+    if checks {
+        header.extend(["expected", "status"]);
+    }
+    if benchmarks {
+        header.extend(["runs", "min", "median", "mean"]);
+    } else {
+        header.push("time");
+    }
+    header.push("input");
+
+    let rows: Vec<Vec<String>> = records
+        .iter()
+        .map(|r| {
+            let mut row = vec![
+                r.day.to_string(),
+                r.part.to_string(),
+                r.answer.to_string(),
+                r.answer.type_name().to_string(),
+            ];
+            if checks {
+                row.push(
+                    r.expected
+                        .as_ref()
+                        .map(Answer::to_string)
+                        .unwrap_or_default(),
+                );
+                row.push(
+                    match r.passed() {
+                        Some(true) => "ok",
+                        Some(false) => "FAIL",
+                        None => "",
+                    }
+                    .to_string(),
+                );
+            }
+            if benchmarks {
+                row.push(r.timings.len().to_string());
+                row.push(Human(r.min()).to_string());
+                row.push(Human(r.median()).to_string());
+                row.push(Human(r.mean()).to_string());
+            } else {
+                row.push(Human(r.mean()).to_string());
+            }
+            row.push(r.input.clone());
+            row
+        })
+        .collect();
+
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .chain([header[i].len()])
+                .max()
+                .unwrap()
+        })
+        .collect();
+    let line = |cells: Vec<&str>| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:width$}", cell))
+            .collect();
+        padded.join("  ").trim_end().to_string()
+    };
+    writeln!(out, "{}", line(header.clone()))?;
+    for row in &rows {
+        writeln!(out, "{}", line(row.iter().map(String::as_str).collect()))?;
+    }
+    Ok(())
+}
+
+pub fn write(records: &[Record], format: Format, out: &mut impl Write) -> io::Result<()> {
+    match format {
+        Format::Json => write_json(records, out),
+        Format::Csv => write_csv(records, out),
+        Format::Table => write_table(records, out),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                day: 3,
+                part: 1,
+                answer: Answer::from(357i64),
+                expected: Some("357".parse().unwrap()),
+                input: "day03_test.txt".to_string(),
+                input_hash: 0xabc,
+                timings: vec![Duration::from_micros(12)],
+            },
+            Record {
+                day: 3,
+                part: 2,
+                answer: Answer::from("1,2"),
+                expected: None,
+                input: "say \"hi\"".to_string(),
+                input_hash: 1,
+                timings: vec![Duration::from_millis(3), Duration::from_millis(1)],
+            },
+        ]
+    }
+
+    fn render(format: Format) -> String {
+        let mut out = Vec::new();
+        write(&records(), format, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_json() {
+        let json = render(Format::Json);
+        assert!(json.starts_with("[\n  {\"day\": 3, \"part\": 1, \"answer\": \"357\", \"answer_type\": \"i64\", \"expected\": \"357\", \"passed\": true,"));
+        assert!(json.contains("\"expected\": null, \"passed\": null, \"input\": \"say \\\"hi\\\"\", \"input_hash\": \"0000000000000001\", \"runs\": 2, \"min_ns\": 1000000, \"median_ns\": 3000000, \"mean_ns\": 2000000,"));
+        assert!(json.ends_with(&format!("\"version\": \"{}\"}}\n]\n", VERSION)));
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = render(Format::Csv);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], FIELDS.join(","));
+        assert_eq!(
+            lines[1],
+            format!(
+                "3,1,357,i64,357,true,day03_test.txt,0000000000000abc,1,12000,12000,12000,{}",
+                VERSION
+            )
+        );
+        assert!(lines[2].starts_with("3,2,\"1,2\",String,,,\"say \"\"hi\"\"\","));
+    }
+
+    #[test]
+    fn test_read_csv() {
+        let rows = read_csv(&render(Format::Csv));
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1]["answer"], "1,2");
+        assert_eq!(rows[1]["input"], "say \"hi\"");
+        assert_eq!(rows[0]["passed"], "true");
+        assert_eq!(rows[1]["mean_ns"], "2000000");
+    }
+
+    #[test]
+    fn test_table() {
+        let table = render(Format::Table);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines[0],
+            "day  part  answer  type    expected  status  runs  min     median  mean    input"
+        );
+        assert_eq!(
+            lines[1],
+            "3    1     357     i64     357       ok      1     12.0µs  12.0µs  12.0µs  day03_test.txt"
+        );
+    }
+
+    #[test]
+    fn test_human() {
+        assert_eq!(Human(Duration::from_nanos(850)).to_string(), "850ns");
+        assert_eq!(Human(Duration::from_nanos(4_560_000)).to_string(), "4.56ms");
+        assert_eq!(format!("{:>6}", Human(Duration::from_secs(1))), " 1.00s");
+    }
+}
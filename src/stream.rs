@@ -0,0 +1,89 @@
+//! Reading puzzle inputs one record at a time from any [`BufRead`], for
+//! inputs too big to load into one `&str`. Records share a single buffer,
+//! so memory stays bounded by the longest record, not the input.
+
+use std::io::{self, BufRead};
+
+pub struct Records<R> {
+    reader: R,
+    delimiter: u8,
+    buf: Vec<u8>,
+}
+
+impl<R: BufRead> Records<R> {
+    /// Lines, split like [`str::lines`]: on `\n`, with a trailing `\r`
+    /// removed and no empty record after a final newline.
+    pub fn lines(reader: R) -> Self {
+        Self::split(reader, b'\n')
+    }
+
+    /// Records separated by `delimiter`, such as the commas in day 2.
+    pub fn split(reader: R, delimiter: u8) -> Self {
+        Records {
+            reader,
+            delimiter,
+            buf: Vec::new(),
+        }
+    }
+
+    /// The next record without its delimiter, or `None` at the end of the
+    /// input. Fails on read errors and on records that aren't UTF-8.
+    pub fn next_record(&mut self) -> io::Result<Option<&str>> {
+        self.buf.clear();
+        if self.reader.read_until(self.delimiter, &mut self.buf)? == 0 {
+            return Ok(None);
+        }
+        let mut record = self.buf.as_slice();
+        if let Some(rest) = record.strip_suffix(&[self.delimiter]) {
+            record = rest;
+        }
+        if self.delimiter == b'\n'
+            && let Some(rest) = record.strip_suffix(b"\r")
+        {
+            record = rest;
+        }
+        std::str::from_utf8(record)
+            .map(Some)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+
+    fn collect<R: BufRead>(mut records: Records<R>) -> Vec<String> {
+        let mut out = Vec::new();
+        while let Some(record) = records.next_record().unwrap() {
+            out.push(record.to_string());
+        }
+        out
+    }
+
+    #[test]
+    fn test_lines_match_str_lines() {
+        for text in ["", "a", "a\n", "a\r\nb\n\nc", "\n\n", "é\r\n"] {
+            // A one-byte buffer makes every record span several reads.
+            let reader = BufReader::with_capacity(1, text.as_bytes());
+            assert_eq!(
+                collect(Records::lines(reader)),
+                text.lines().collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_split() {
+        let records = Records::split("1-2,3-4\n".as_bytes(), b',');
+        assert_eq!(collect(records), ["1-2", "3-4\n"]);
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let mut records = Records::lines(&b"ok\n\xff\n"[..]);
+        assert_eq!(records.next_record().unwrap(), Some("ok"));
+        let error = records.next_record().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
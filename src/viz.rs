@@ -0,0 +1,184 @@
+//! Terminal visualization for the grid-based days. A day turns its solve
+//! into a sequence of [`Frame`]s; a [`Player`] draws them with ANSI colors,
+//! highlighting the cells that changed since the previous frame, either at a
+//! fixed frame rate or one frame per press of Enter. Frames can also be
+//! dumped as plain text.
+
+use crate::utils::GridDisplay;
+use std::io::{self, BufRead, Write};
+use std::thread;
+use std::time::Duration;
+
+/// ANSI foreground color code for a cell, or `None` to leave it uncolored.
+pub type Palette = fn(char) -> Option<u8>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub grid: Vec<Vec<char>>,
+    pub caption: String,
+}
+
+impl Frame {
+    pub fn new(grid: Vec<Vec<char>>, caption: impl Into<String>) -> Self {
+        Frame {
+            grid,
+            caption: caption.into(),
+        }
+    }
+
+    /// Whether the cell at `(x, y)` differs from the same cell in `previous`.
+    /// A cell only one of the frames has counts as changed.
+    pub fn changed(&self, previous: &Frame, x: usize, y: usize) -> bool {
+        let cell = |frame: &Frame| frame.grid.get(y).and_then(|row| row.get(x)).copied();
+        cell(self) != cell(previous)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pace {
+    /// Frames per second; must be positive and finite.
+    Fps(f64),
+    /// Wait for Enter before every frame.
+    Step,
+}
+
+pub struct Player {
+    palette: Palette,
+    pace: Pace,
+    highlight: bool,
+}
+
+impl Player {
+    pub fn new(palette: Palette) -> Self {
+        Player {
+            palette,
+            pace: Pace::Fps(10.0),
+            highlight: true,
+        }
+    }
+
+    pub fn pace(mut self, pace: Pace) -> Self {
+        self.pace = pace;
+        self
+    }
+
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// One frame as ANSI text: caption, then the grid, with changed cells in
+    /// bold reverse video.
+    pub fn render(&self, frame: &Frame, previous: Option<&Frame>) -> String {
+        let mut out = format!("{}\x1b[K\n", frame.caption);
+        for (y, row) in frame.grid.iter().enumerate() {
+            for (x, &c) in row.iter().enumerate() {
+                let changed = self.highlight && previous.is_some_and(|p| frame.changed(p, x, y));
+                match ((self.palette)(c), changed) {
+                    (None, false) => out.push(c),
+                    (None, true) => out.push_str(&format!("\x1b[1;7m{}\x1b[0m", c)),
+                    (Some(color), false) => out.push_str(&format!("\x1b[{}m{}\x1b[0m", color, c)),
+                    (Some(color), true) => {
+                        out.push_str(&format!("\x1b[1;7;{}m{}\x1b[0m", color, c))
+                    }
+                }
+            }
+            out.push_str("\x1b[K\n");
+        }
+        out
+    }
+
+    /// Draws every frame in place, clearing the screen once up front. In
+    /// step mode, reading `q` or end of input stops early.
+    pub fn play(
+        &self,
+        frames: &[Frame],
+        out: &mut impl Write,
+        input: &mut impl BufRead,
+    ) -> io::Result<()> {
+        write!(out, "\x1b[2J")?;
+        let mut previous = None;
+        for (i, frame) in frames.iter().enumerate() {
+            write!(out, "\x1b[H{}", self.render(frame, previous))?;
+            writeln!(out, "frame {}/{}\x1b[K", i + 1, frames.len())?;
+            out.flush()?;
+            match self.pace {
+                Pace::Fps(fps) => thread::sleep(Duration::from_secs_f64(1.0 / fps)),
+                Pace::Step if i + 1 < frames.len() => {
+                    let mut line = String::new();
+                    if input.read_line(&mut line)? == 0 || line.trim() == "q" {
+                        break;
+                    }
+                }
+                Pace::Step => {}
+            }
+            previous = Some(frame);
+        }
+        Ok(())
+    }
+}
+
+/// Writes every frame as plain text under a `frame i/n: caption` header.
+pub fn dump(frames: &[Frame], out: &mut impl Write) -> io::Result<()> {
+    for (i, frame) in frames.iter().enumerate() {
+        writeln!(out, "frame {}/{}: {}", i + 1, frames.len(), frame.caption)?;
+        write!(out, "{}", GridDisplay(&frame.grid))?;
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(rows: &str, caption: &str) -> Frame {
+        Frame::new(
+            rows.lines().map(|row| row.chars().collect()).collect(),
+            caption,
+        )
+    }
+
+    #[test]
+    fn test_render_highlights_changes() {
+        let player = Player::new(|c| (c == '@').then_some(32));
+        let before = frame("@.\n..", "before");
+        let after = frame("@x\n..\n.", "after");
+
+        assert_eq!(
+            player.render(&before, None),
+            "before\x1b[K\n\x1b[32m@\x1b[0m.\x1b[K\n..\x1b[K\n"
+        );
+        assert_eq!(
+            player.render(&after, Some(&before)),
+            "after\x1b[K\n\x1b[32m@\x1b[0m\x1b[1;7mx\x1b[0m\x1b[K\n..\x1b[K\n\x1b[1;7m.\x1b[0m\x1b[K\n"
+        );
+        assert_eq!(
+            player.highlight(false).render(&after, Some(&before)),
+            "after\x1b[K\n\x1b[32m@\x1b[0mx\x1b[K\n..\x1b[K\n.\x1b[K\n"
+        );
+    }
+
+    #[test]
+    fn test_step_stops_on_quit() {
+        let frames = [frame("a", "1"), frame("b", "2"), frame("c", "3")];
+        let mut out = Vec::new();
+        Player::new(|_| None)
+            .pace(Pace::Step)
+            .play(&frames, &mut out, &mut "\nq\n".as_bytes())
+            .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("frame 2/3"));
+        assert!(!out.contains("frame 3/3"));
+    }
+
+    #[test]
+    fn test_dump() {
+        let mut out = Vec::new();
+        dump(&[frame("@.", "start"), frame("x.", "end")], &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "frame 1/2: start\n@.\n\nframe 2/2: end\nx.\n\n"
+        );
+    }
+}
@@ -0,0 +1,137 @@
+//! A polling file watcher using only std: it snapshots the size and
+//! modification time of every file under some directories and compares
+//! snapshots, so no platform notification API is needed.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Size and modification time of every file under the watched directories.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, (u64, Option<SystemTime>)>);
+
+impl Snapshot {
+    /// Walks `roots` recursively, skipping hidden files and directories.
+    /// Roots that don't exist are left out.
+    pub fn take(roots: &[PathBuf]) -> Self {
+        let mut files = BTreeMap::new();
+        let mut stack: Vec<PathBuf> = roots.to_vec();
+        while let Some(dir) = stack.pop() {
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                if entry.file_name().to_string_lossy().starts_with('.') {
+                    continue;
+                }
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+                if metadata.is_dir() {
+                    stack.push(entry.path());
+                } else {
+                    files.insert(entry.path(), (metadata.len(), metadata.modified().ok()));
+                }
+            }
+        }
+        Snapshot(files)
+    }
+
+    /// Files added, removed or modified between `self` and `newer`.
+    pub fn changes(&self, newer: &Snapshot) -> Vec<PathBuf> {
+        let mut changed: Vec<PathBuf> = newer
+            .0
+            .iter()
+            .filter(|(path, stamp)| self.0.get(*path) != Some(stamp))
+            .map(|(path, _)| path.clone())
+            .collect();
+        changed.extend(
+            self.0
+                .keys()
+                .filter(|path| !newer.0.contains_key(*path))
+                .cloned(),
+        );
+        changed.sort();
+        changed
+    }
+}
+
+pub struct Watcher {
+    roots: Vec<PathBuf>,
+    interval: Duration,
+    last: Snapshot,
+}
+
+impl Watcher {
+    pub fn new(roots: &[&Path], interval: Duration) -> Self {
+        let roots: Vec<PathBuf> = roots.iter().map(|root| root.to_path_buf()).collect();
+        let last = Snapshot::take(&roots);
+        Watcher {
+            roots,
+            interval,
+            last,
+        }
+    }
+
+    /// Blocks until something changes, then waits for the files to settle
+    /// for one more interval so an editor's save counts once.
+    pub fn wait(&mut self) -> Vec<PathBuf> {
+        loop {
+            thread::sleep(self.interval);
+            let current = Snapshot::take(&self.roots);
+            if current == self.last {
+                continue;
+            }
+            let mut settled = current;
+            loop {
+                thread::sleep(self.interval);
+                let next = Snapshot::take(&self.roots);
+                if next == settled {
+                    break;
+                }
+                settled = next;
+            }
+            let changes = self.last.changes(&settled);
+            self.last = settled;
+            return changes;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    #[test]
+    fn test_snapshot_changes() {
+        let root = env::temp_dir().join(format!("aoc-watch-{}", process::id()));
+        fs::create_dir_all(root.join("nested")).unwrap();
+        fs::write(root.join("a.txt"), "a").unwrap();
+        fs::write(root.join(".hidden"), "h").unwrap();
+        let roots = [root.clone()];
+
+        let before = Snapshot::take(&roots);
+        assert!(before.changes(&Snapshot::take(&roots)).is_empty());
+
+        fs::write(root.join("a.txt"), "longer").unwrap();
+        fs::write(root.join("nested/b.txt"), "b").unwrap();
+        fs::write(root.join(".hidden"), "ignored").unwrap();
+        let after = Snapshot::take(&roots);
+        assert_eq!(
+            before.changes(&after),
+            [root.join("a.txt"), root.join("nested/b.txt")]
+        );
+
+        fs::remove_file(root.join("nested/b.txt")).unwrap();
+        assert_eq!(
+            after.changes(&Snapshot::take(&roots)),
+            [root.join("nested/b.txt")]
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
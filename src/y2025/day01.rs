@@ -0,0 +1,294 @@
+use crate::explain::Trace;
+use crate::online::Online;
+use crate::registry::Answer;
+use crate::stream::Records;
+use crate::utils::read_lines;
+use std::fmt;
+use std::io::{self, BufRead};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseRotationError {
+    /// Not `L` or `R` followed by a distance that fits in an `i64`.
+    Invalid(String),
+    /// A rotation that failed to parse, with its 1-based line number.
+    Line(usize, Box<ParseRotationError>),
+}
+
+impl fmt::Display for ParseRotationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseRotationError::Invalid(s) => write!(f, "invalid rotation {:?}", s),
+            ParseRotationError::Line(line, e) => write!(f, "line {}: {}", line, e),
+        }
+    }
+}
+
+impl std::error::Error for ParseRotationError {}
+
+pub fn parse_line(input: &str) -> Result<i64, ParseRotationError> {
+    let invalid = || ParseRotationError::Invalid(input.to_string());
+    let mut chars = input.chars();
+    let first = chars.next().ok_or_else(invalid)?;
+    let rest = chars.as_str();
+    if !rest.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let number: i64 = rest.parse().map_err(|_| invalid())?;
+
+    match first {
+        'R' => Ok(number),
+        'L' => Ok(-number),
+        _ => Err(invalid()),
+    }
+}
+
+/// Every rotation in `input`, as signed distances with left negative.
+pub fn parse_rotations(input: &str) -> Result<Vec<i64>, ParseRotationError> {
+    read_lines(input)
+        .iter()
+        .enumerate()
+        .map(|(i, line)| parse_line(line).map_err(|e| ParseRotationError::Line(i + 1, Box::new(e))))
+        .collect()
+}
+
+fn rotations(input: &str) -> Vec<i64> {
+    parse_rotations(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn turn_dial(dial: i64, change: i64) -> i64 {
+    let dial_pos = dial + change % 100;
+    dial_pos.rem_euclid(100)
+}
+
+pub fn turn_dial_counter(dial: i64, change: i64) -> (i64, i64) {
+    let mut counter = change.abs() / 100;
+
+    let remainder = change % 100;
+
+    let new_pos = (dial + remainder).rem_euclid(100);
+
+    if remainder > 0 {
+        if new_pos == 0 || new_pos < dial {
+            counter += 1;
+        }
+    } else if remainder < 0 && (new_pos == 0 || (new_pos > dial && dial != 0)) {
+        counter += 1;
+    }
+
+    (new_pos, counter)
+}
+
+/// The dial and both passwords so far, turned one rotation at a time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dial {
+    position: i64,
+    /// Rotations that ended on zero, the part 1 password.
+    stops: i64,
+    /// Clicks that passed zero, the part 2 password. Wide enough that no
+    /// input that fits in memory can overflow it.
+    clicks: i128,
+}
+
+impl Dial {
+    pub fn new() -> Self {
+        Dial {
+            position: 50,
+            stops: 0,
+            clicks: 0,
+        }
+    }
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Online for Dial {
+    fn push_line(&mut self, line: &str) -> Result<(), String> {
+        let change = parse_line(line).map_err(|e| e.to_string())?;
+        let (position, clicks) = turn_dial_counter(self.position, change);
+        self.position = position;
+        self.stops += (position == 0) as i64;
+        self.clicks += clicks as i128;
+        Ok(())
+    }
+
+    fn current_answer(&self, part: u32) -> Option<Answer> {
+        match part {
+            1 => Some(Answer::from(self.stops)),
+            2 => Some(Answer::from(self.clicks)),
+            _ => None,
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Dial::new();
+    }
+}
+
+pub fn part1(input: &str) -> i64 {
+    part1_traced(input, &mut Trace::off())
+}
+
+/// [`part1`], noting where every rotation leaves the dial.
+pub fn part1_traced(input: &str, trace: &mut Trace) -> i64 {
+    let mut dial: i64 = 50;
+    let mut password: i64 = 0;
+    for (line, change) in read_lines(input).iter().zip(rotations(input)) {
+        dial = turn_dial(dial, change);
+        trace.note(|| format!("{}: dial at {}", line, dial));
+        if dial == 0 {
+            password += 1;
+        }
+    }
+    password
+}
+
+/// Counted in `i128`: a single rotation can pass zero almost `i64::MAX / 100`
+/// times, so a few hundred huge rotations would overflow an `i64`.
+pub fn part2(input: &str) -> i128 {
+    part2_traced(input, &mut Trace::off())
+}
+
+/// [`part2`], noting every rotation and how often it passes zero.
+pub fn part2_traced(input: &str, trace: &mut Trace) -> i128 {
+    let mut dial: i64 = 50;
+    let mut counter: i64;
+    let mut password: i128 = 0;
+    for (line, change) in read_lines(input).iter().zip(rotations(input)) {
+        let before = dial;
+        (dial, counter) = turn_dial_counter(dial, change);
+        trace.note(|| {
+            format!(
+                "{}: {} -> {}, passes zero {} times",
+                line, before, dial, counter
+            )
+        });
+        password += counter as i128;
+    }
+    password
+}
+
+/// [`parse_line`] for line `number` of a stream, failing the read on a bad
+/// rotation.
+fn parse_streamed(line: &str, number: usize) -> io::Result<i64> {
+    parse_line(line).map_err(|e| {
+        let e = ParseRotationError::Line(number, Box::new(e));
+        io::Error::new(io::ErrorKind::InvalidData, e)
+    })
+}
+
+/// [`part1`] reading one line at a time from `reader`.
+pub fn part1_stream(reader: impl BufRead) -> io::Result<i64> {
+    let mut lines = Records::lines(reader);
+    let mut dial: i64 = 50;
+    let mut password: i64 = 0;
+    let mut number = 0;
+    while let Some(line) = lines.next_record()? {
+        number += 1;
+        let change = parse_streamed(line, number)?;
+        dial = turn_dial(dial, change);
+        if dial == 0 {
+            password += 1;
+        }
+    }
+    Ok(password)
+}
+
+/// [`part2`] reading one line at a time from `reader`.
+pub fn part2_stream(reader: impl BufRead) -> io::Result<i128> {
+    let mut lines = Records::lines(reader);
+    let mut dial: i64 = 50;
+    let mut counter: i64;
+    let mut password: i128 = 0;
+    let mut number = 0;
+    while let Some(line) = lines.next_record()? {
+        number += 1;
+        let change = parse_streamed(line, number)?;
+        (dial, counter) = turn_dial_counter(dial, change);
+        password += counter as i128;
+    }
+    Ok(password)
+}
+
+/// Clicks the dial one step at a time, counting every stop on zero.
+#[cfg(test)]
+fn part2_reference(input: &str) -> i128 {
+    let mut dial: i64 = 50;
+    let mut password: i128 = 0;
+    for change in rotations(input) {
+        for _ in 0..change.abs() {
+            dial = turn_dial(dial, change.signum());
+            if dial == 0 {
+                password += 1;
+            }
+        }
+    }
+    password
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::Differential;
+    use crate::generators;
+    use crate::online;
+
+    #[test]
+    fn test_part2_matches_reference() {
+        Differential::new("day01_part2", generators::day01).check(part2_reference, part2);
+    }
+
+    #[test]
+    fn test_dial() {
+        let mut dial = Dial::new();
+        assert!(dial.push_line("X5").is_err());
+        dial.push_line("L50").unwrap();
+        assert_eq!(dial.current_answer(1), Some(Answer::from(1i64)));
+        dial.reset();
+        assert_eq!(dial, Dial::new());
+    }
+
+    #[test]
+    fn test_parse_rotations() {
+        assert_eq!(parse_rotations("L68\nR14\n"), Ok(vec![-68, 14]));
+        for line in ["", "R", "X5", "R+5", "L-5", "R9223372036854775808"] {
+            assert_eq!(
+                parse_line(line),
+                Err(ParseRotationError::Invalid(line.to_string()))
+            );
+        }
+        assert_eq!(
+            parse_rotations("L68\nR 14\n").unwrap_err().to_string(),
+            "line 2: invalid rotation \"R 14\""
+        );
+    }
+
+    #[test]
+    fn test_stream_rejects_malformed_lines() {
+        for stream in [
+            |input: &[u8]| part1_stream(input).map(i128::from),
+            |input: &[u8]| part2_stream(input),
+        ] {
+            let e = stream(b"L68\nX5\n").unwrap_err();
+            assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+            assert_eq!(e.to_string(), "line 2: invalid rotation \"X5\"");
+        }
+    }
+
+    #[test]
+    fn test_many_huge_rotations() {
+        let input = "R9223372036854775807\n".repeat(110);
+        // Every rotation goes round i64::MAX / 100 times, then the leftover
+        // 7 clicks each take the dial from 50 past zero 8 times in all.
+        let clicks = 110 * (i64::MAX as i128 / 100) + 8;
+        assert_eq!(part2(&input), clicks);
+        assert_eq!(part2_stream(input.as_bytes()).unwrap(), clicks);
+        assert_eq!(
+            online::answers(&mut Dial::new(), &input).unwrap()[1],
+            Some(clicks.to_string())
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use crate::explain::{self, Trace};
+use crate::math;
+use crate::stream::Records;
+use std::io::{self, BufRead};
+use std::num::ParseIntError;
+use std::ops::RangeInclusive;
+
+/// Multiplying a `block`-digit number by this repeats it `times` times, so
+/// it's 1 followed by `block - 1` zeros, `times` times over: 1001, 10101...
+fn repeater(block: u32, times: u32) -> i64 {
+    (0..times).map(|i| 10i64.pow(block * i)).sum()
+}
+
+/// Whether the digits of `n` are some block repeated exactly `times` times,
+/// which is whether `n` is a multiple of the matching [`repeater`].
+pub fn repeats(n: i64, times: u32) -> bool {
+    let len = math::digit_count(n, 10);
+    len.is_multiple_of(times) && n % repeater(len / times, times) == 0
+}
+
+pub fn is_periodic(n: i64) -> bool {
+    (2..=math::digit_count(n, 10)).any(|times| repeats(n, times))
+}
+
+pub fn is_twice(n: i64) -> bool {
+    repeats(n, 2)
+}
+
+pub fn parse_ranges(input: &str) -> Result<Vec<RangeInclusive<i64>>, ParseIntError> {
+    input
+        .split(',')
+        .filter_map(|part| part.split_once('-'))
+        .map(|(start, end)| Ok(start.trim().parse()?..=end.trim().parse()?))
+        .collect()
+}
+
+fn sum_range(range: RangeInclusive<i64>, is_invalid: fn(i64) -> bool, trace: &mut Trace) -> i64 {
+    let (start, end) = (*range.start(), *range.end());
+    let mut found = Vec::new();
+    let mut sum: i64 = 0;
+    for num in range {
+        if is_invalid(num) {
+            if trace.enabled() {
+                found.push(num);
+            }
+            sum += num;
+        }
+    }
+    trace.note(|| match found.len() {
+        0 => format!("{}-{}: no invalid IDs", start, end),
+        _ => format!(
+            "{}-{}: {} (sum {})",
+            start,
+            end,
+            explain::list(&found, 10),
+            sum
+        ),
+    });
+    sum
+}
+
+pub fn sum_invalid(input: &str, is_invalid: fn(i64) -> bool) -> i64 {
+    sum_invalid_traced(input, is_invalid, &mut Trace::off())
+}
+
+/// [`sum_invalid`], noting the invalid IDs found in every range.
+pub fn sum_invalid_traced(input: &str, is_invalid: fn(i64) -> bool, trace: &mut Trace) -> i64 {
+    let ranges = parse_ranges(input).expect("Not a number");
+    ranges
+        .into_iter()
+        .map(|range| sum_range(range, is_invalid, trace))
+        .sum()
+}
+
+/// [`sum_invalid`] reading one range at a time from `reader`, so neither the
+/// input nor the list of ranges is held in memory.
+pub fn sum_invalid_stream(reader: impl BufRead, is_invalid: fn(i64) -> bool) -> io::Result<i64> {
+    let mut records = Records::split(reader, b',');
+    let mut sum: i64 = 0;
+    while let Some(record) = records.next_record()? {
+        let mut ranges = parse_ranges(record).map_err(|e| {
+            let message = format!("invalid range {:?}: {}", record.trim(), e);
+            io::Error::new(io::ErrorKind::InvalidData, message)
+        })?;
+        if let Some(range) = ranges.pop() {
+            sum += sum_range(range, is_invalid, &mut Trace::off());
+        }
+    }
+    Ok(sum)
+}
+
+pub fn part1(input: &str) -> i64 {
+    sum_invalid(input, is_twice)
+}
+
+pub fn part2(input: &str) -> i64 {
+    sum_invalid(input, is_periodic)
+}
+
+pub fn part1_stream(reader: impl BufRead) -> io::Result<i64> {
+    sum_invalid_stream(reader, is_twice)
+}
+
+pub fn part2_stream(reader: impl BufRead) -> io::Result<i64> {
+    sum_invalid_stream(reader, is_periodic)
+}
+
+/// Whether `s` is some block repeated exactly `times` times.
+#[cfg(test)]
+fn is_repeated(s: &str, times: usize) -> bool {
+    s.len().is_multiple_of(times) && s[..s.len() / times].repeat(times) == s
+}
+
+#[cfg(test)]
+fn part1_reference(input: &str) -> i64 {
+    sum_invalid(input, |n| is_repeated(&n.to_string(), 2))
+}
+
+#[cfg(test)]
+fn part2_reference(input: &str) -> i64 {
+    sum_invalid(input, |n| {
+        let s = n.to_string();
+        (2..=s.len()).any(|times| is_repeated(&s, times))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::Differential;
+    use crate::generators;
+
+    #[test]
+    fn test_matches_reference() {
+        Differential::new("day02_part1", generators::day02)
+            .separator(',')
+            .check(part1_reference, part1);
+        Differential::new("day02_part2", generators::day02)
+            .separator(',')
+            .check(part2_reference, part2);
+    }
+
+    #[test]
+    fn test_stream_rejects_malformed_ranges() {
+        for stream in [
+            |input: &[u8]| part1_stream(input),
+            |input: &[u8]| part2_stream(input),
+        ] {
+            let e = stream(b"11-22,95-x\n").unwrap_err();
+            assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+            assert_eq!(
+                e.to_string(),
+                "invalid range \"95-x\": invalid digit found in string"
+            );
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use crate::bigint::BigUint;
+use crate::explain::Trace;
+use crate::math;
+use crate::online::Online;
+use crate::registry::Answer;
+use crate::stream::Records;
+use std::fmt;
+use std::io::{self, BufRead};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BankError {
+    /// A bank with fewer batteries than have to be turned on.
+    TooShort { batteries: usize, needed: usize },
+    /// A bank that was rejected, with its 1-based line number.
+    Line(usize, Box<BankError>),
+}
+
+impl fmt::Display for BankError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BankError::TooShort { batteries, needed } => write!(
+                f,
+                "bank has {} batteries but {} are needed",
+                batteries, needed
+            ),
+            BankError::Line(line, e) => write!(f, "line {}: {}", line, e),
+        }
+    }
+}
+
+impl std::error::Error for BankError {}
+
+pub fn parse_bank_n(input: &str, n: usize) -> Result<i64, BankError> {
+    parse_bank_n_traced(input, n, &mut Trace::off())
+}
+
+/// The positions and digits of the `n` batteries that make the largest
+/// joltage.
+fn pick(input: &str, n: usize) -> Result<Vec<(usize, u32)>, BankError> {
+    let digits: Vec<u32> = input.chars().filter_map(|c| c.to_digit(10)).collect();
+    if digits.len() < n {
+        return Err(BankError::TooShort {
+            batteries: digits.len(),
+            needed: n,
+        });
+    }
+
+    let mut picked = Vec::with_capacity(n);
+    let mut current_pos = 0;
+    for i in 0..n {
+        let remaining_needed = n - 1 - i;
+        let limit = digits.len() - remaining_needed;
+        let (offset_idx, &val) = digits[current_pos..limit]
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|&(_, v)| v)
+            .expect("window always holds a battery");
+        current_pos += offset_idx + 1;
+        picked.push((current_pos - 1, val));
+    }
+    Ok(picked)
+}
+
+/// [`parse_bank_n`], noting which batteries were picked and where.
+pub fn parse_bank_n_traced(input: &str, n: usize, trace: &mut Trace) -> Result<i64, BankError> {
+    let picked = pick(input, n)?;
+    let digits: Vec<i64> = picked.iter().map(|&(_, digit)| digit.into()).collect();
+    let result = math::from_digits(&digits, 10).expect("joltage overflows i64");
+    trace.note(|| {
+        let picked: Vec<String> = picked
+            .iter()
+            .map(|(pos, digit)| format!("{}@{}", digit, pos))
+            .collect();
+        format!("{}: picked {} -> {}", input, picked.join(" "), result)
+    });
+    Ok(result)
+}
+
+/// [`parse_bank_n`] in exact arithmetic, for picking more batteries than
+/// an `i64` has digits.
+pub fn parse_bank_exact(input: &str, n: usize) -> Result<BigUint, BankError> {
+    let ten = BigUint::from(10u64);
+    Ok(pick(input, n)?
+        .into_iter()
+        .fold(BigUint::zero(), |joltage, (_, digit)| {
+            joltage * &ten + BigUint::from(digit as u64)
+        }))
+}
+
+/// Applies `parse` to every bank in `input` and sums the joltages, tagging
+/// a rejected bank with its line number.
+fn sum_lines<T: std::iter::Sum>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, BankError>,
+) -> Result<T, BankError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|e| BankError::Line(i + 1, Box::new(e))))
+        .sum()
+}
+
+pub fn sum_banks_exact(input: &str, n: usize) -> Result<BigUint, BankError> {
+    sum_lines(input, |line| parse_bank_exact(line, n))
+}
+
+/// Sums the joltage of every bank in `input`, noting each one's digits.
+pub fn sum_banks_traced(input: &str, n: usize, trace: &mut Trace) -> Result<i64, BankError> {
+    sum_lines(input, |line| parse_bank_n_traced(line, n, trace))
+}
+
+pub fn sum_banks(input: &str, n: usize) -> Result<i64, BankError> {
+    sum_banks_traced(input, n, &mut Trace::off())
+}
+
+pub fn part1(input: &str) -> i64 {
+    sum_banks(input, 2).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn part2(input: &str) -> i64 {
+    sum_banks(input, 12).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Joltage summed over the banks so far, with two and with twelve batteries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Banks {
+    joltage: [i64; 2],
+}
+
+impl Online for Banks {
+    fn push_line(&mut self, line: &str) -> Result<(), String> {
+        let two = parse_bank_n(line, 2).map_err(|e| e.to_string())?;
+        let twelve = parse_bank_n(line, 12).map_err(|e| e.to_string())?;
+        self.joltage[0] += two;
+        self.joltage[1] += twelve;
+        Ok(())
+    }
+
+    fn current_answer(&self, part: u32) -> Option<Answer> {
+        match part {
+            1 | 2 => Some(Answer::from(self.joltage[part as usize - 1])),
+            _ => None,
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Banks::default();
+    }
+}
+
+/// Sums the joltage of every bank read from `reader`, one line at a time.
+pub fn sum_banks_stream(reader: impl BufRead, n: usize) -> io::Result<i64> {
+    let mut lines = Records::lines(reader);
+    let mut sum = 0;
+    let mut number = 0;
+    while let Some(line) = lines.next_record()? {
+        number += 1;
+        sum += parse_bank_n(line, n).map_err(|e| {
+            let e = BankError::Line(number, Box::new(e));
+            io::Error::new(io::ErrorKind::InvalidData, e)
+        })?;
+    }
+    Ok(sum)
+}
+
+pub fn part1_stream(reader: impl BufRead) -> io::Result<i64> {
+    sum_banks_stream(reader, 2)
+}
+
+pub fn part2_stream(reader: impl BufRead) -> io::Result<i64> {
+    sum_banks_stream(reader, 12)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_beyond_i64() {
+        let bank = "1".repeat(10) + &"9".repeat(30) + "8";
+        assert_eq!(
+            parse_bank_exact(&bank, 31).unwrap().to_string(),
+            "9".repeat(30) + "8"
+        );
+    }
+
+    #[test]
+    fn test_short_bank_is_rejected() {
+        let short = BankError::TooShort {
+            batteries: 2,
+            needed: 3,
+        };
+        assert_eq!(parse_bank_n("12", 3), Err(short.clone()));
+        assert_eq!(parse_bank_exact("12", 3), Err(short));
+        assert_eq!(parse_bank_n("12", 2), Ok(12));
+
+        let input = include_str!("../../inputs/regressions/fuzz/day03_short_bank.txt");
+        assert_eq!(sum_banks(input, 2), Ok(45));
+        assert_eq!(
+            sum_banks(input, 12).unwrap_err().to_string(),
+            "line 1: bank has 5 batteries but 12 are needed"
+        );
+        let e = part2_stream(input.as_bytes()).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        let mut banks = Banks::default();
+        assert!(banks.push_line(input.trim_end()).is_err());
+        assert_eq!(banks, Banks::default());
+    }
+}
@@ -0,0 +1,269 @@
+use crate::cycle;
+use crate::image::{self, CellColors, Indexed, Rgb};
+use crate::repl::Session;
+use crate::utils::{GridDisplay, read_grid};
+use crate::viz::Frame;
+
+pub fn is_roll(grid: &[Vec<char>], x: isize, y: isize) -> bool {
+    if x < 0 || y < 0 {
+        return false;
+    }
+    if let Some(line) = grid.get(y as usize)
+        && let Some(point) = line.get(x as usize)
+    {
+        return *point == '@';
+    }
+    false
+}
+
+pub fn check_surrounding(grid: &[Vec<char>], x: isize, y: isize) -> i64 {
+    let mut sum = 0;
+    for xx in -1..=1 {
+        for yy in -1..=1 {
+            if xx == 0 && yy == 0 {
+                continue;
+            }
+            if is_roll(grid, x + xx, y + yy) {
+                sum += 1;
+            }
+        }
+    }
+    sum
+}
+
+pub fn count_rolls(grid: &[Vec<char>]) -> i64 {
+    let mut sum = 0;
+    for (y, line) in grid.iter().enumerate() {
+        for (x, c) in line.iter().enumerate() {
+            if *c == '.' {
+                continue;
+            }
+            let surroundings = check_surrounding(grid, x as isize, y as isize);
+            if surroundings < 4 {
+                sum += 1;
+            }
+        }
+    }
+    sum
+}
+
+pub fn count_rolls_edit(grid: &mut [Vec<char>]) -> i64 {
+    let mut sum = 0;
+    let mut to_change = Vec::new();
+
+    for (y, line) in grid.iter().enumerate() {
+        for (x, c) in line.iter().enumerate() {
+            if *c != '@' {
+                continue;
+            }
+
+            let surroundings = check_surrounding(grid, x as isize, y as isize);
+
+            if surroundings < 4 {
+                to_change.push((x, y, surroundings));
+                sum += 1;
+            }
+        }
+    }
+
+    for (x, y, _) in to_change {
+        grid[y][x] = 'x';
+    }
+    sum
+}
+
+/// The grid before any removals and after every round of them, with the
+/// rolls removed so far marked `x`.
+pub fn frames(input: &str) -> Vec<Frame> {
+    let mut grid = read_grid(input);
+    let rolls = grid.iter().flatten().filter(|&&c| c == '@').count();
+    let mut frames = vec![Frame::new(grid.clone(), format!("{} rolls", rolls))];
+    let mut total = 0;
+    for round in 1.. {
+        let removed = count_rolls_edit(&mut grid);
+        if removed == 0 {
+            break;
+        }
+        total += removed;
+        let caption = format!(
+            "round {}: removed {} rolls, {} in total",
+            round, removed, total
+        );
+        frames.push(Frame::new(grid.clone(), caption));
+    }
+    frames
+}
+
+/// The grid once no roll can be removed, with the removed ones marked `x`,
+/// and the number of rounds it took to get there.
+pub fn steady_state(input: &str) -> (Vec<Vec<char>>, usize) {
+    let cycle = cycle::find_cycle(read_grid(input), |grid| {
+        let mut grid = grid.clone();
+        count_rolls_edit(&mut grid);
+        grid
+    });
+    // Removing a roll never brings one back, so the grid can only settle.
+    assert_eq!(cycle.length, 1);
+    (cycle.entry, cycle.start)
+}
+
+/// Rolls green, removed rolls red, empty floor dimmed.
+pub fn palette(c: char) -> Option<u8> {
+    match c {
+        '@' => Some(32),
+        'x' => Some(31),
+        '.' => Some(90),
+        _ => None,
+    }
+}
+
+/// The removal rounds as a GIF, every cell drawn `scale` pixels wide and
+/// each frame shown for `delay` hundredths of a second.
+pub fn animation(input: &str, scale: usize, delay: u16) -> Vec<u8> {
+    let colors = CellColors {
+        background: Rgb(24, 24, 24),
+        cells: vec![('@', Rgb(64, 192, 64)), ('x', Rgb(208, 64, 64))],
+    };
+    let images: Vec<Indexed> = frames(input)
+        .iter()
+        .map(|frame| Indexed::from_grid(&frame.grid, scale, &colors))
+        .collect();
+    image::gif(&images, delay)
+}
+
+/// The grid, for removing rolls one round at a time and looking at it.
+pub struct Explorer {
+    grid: Vec<Vec<char>>,
+    round: usize,
+    removed: i64,
+}
+
+impl Explorer {
+    pub fn new(input: &str) -> Self {
+        Explorer {
+            grid: read_grid(input),
+            round: 0,
+            removed: 0,
+        }
+    }
+}
+
+impl Session for Explorer {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("step", "remove every roll a forklift can reach"),
+            ("show", "print the grid, removed rolls marked x"),
+            ("count", "rolls left, and how many can be reached now"),
+        ]
+    }
+
+    fn run(&mut self, command: &str, _args: &[&str]) -> Result<String, String> {
+        match command {
+            "step" => {
+                let removed = count_rolls_edit(&mut self.grid);
+                if removed == 0 {
+                    return Ok(format!(
+                        "nothing left to remove after {} rounds",
+                        self.round
+                    ));
+                }
+                self.round += 1;
+                self.removed += removed;
+                Ok(format!(
+                    "round {}: removed {} rolls, {} in total",
+                    self.round, removed, self.removed
+                ))
+            }
+            "show" => Ok(GridDisplay(&self.grid).to_string().trim_end().to_string()),
+            "count" => {
+                let mut rolls = 0;
+                let mut reachable = 0;
+                for (y, line) in self.grid.iter().enumerate() {
+                    for (x, &c) in line.iter().enumerate() {
+                        if c != '@' {
+                            continue;
+                        }
+                        rolls += 1;
+                        if check_surrounding(&self.grid, x as isize, y as isize) < 4 {
+                            reachable += 1;
+                        }
+                    }
+                }
+                Ok(format!(
+                    "{} rolls left, {} reachable, {} removed",
+                    rolls, reachable, self.removed
+                ))
+            }
+            _ => Err(format!("unknown command {:?}", command)),
+        }
+    }
+}
+
+pub fn part1(input: &str) -> i64 {
+    let grid = read_grid(input);
+    count_rolls(&grid)
+}
+
+pub fn part2(input: &str) -> i64 {
+    let mut grid = read_grid(input);
+    let mut sum = 0;
+    loop {
+        let new_rolls = count_rolls_edit(&mut grid);
+        if new_rolls == 0 {
+            break;
+        }
+        sum += new_rolls;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frames() {
+        let input = include_str!("../../inputs/2025/day04_test.txt");
+        let frames = frames(input);
+        assert_eq!(frames[0].grid, read_grid(input));
+        let last = frames.last().unwrap();
+        let removed = last.grid.iter().flatten().filter(|&&c| c == 'x').count();
+        assert_eq!(removed as i64, part2(input));
+        assert!(last.caption.ends_with(&format!("{} in total", removed)));
+    }
+
+    #[test]
+    fn test_steady_state() {
+        let input = include_str!("../../inputs/2025/day04_test.txt");
+        let (grid, rounds) = steady_state(input);
+        let frames = frames(input);
+        assert_eq!(rounds, frames.len() - 1);
+        assert_eq!(&grid, &frames.last().unwrap().grid);
+        let removed = grid.iter().flatten().filter(|&&c| c == 'x').count();
+        assert_eq!(removed as i64, part2(input));
+    }
+
+    #[test]
+    fn test_explorer() {
+        let input = include_str!("../../inputs/2025/day04_test.txt");
+        let mut explorer = Explorer::new(input);
+        let count = explorer.run("count", &[]).unwrap();
+        assert!(count.contains(&format!(", {} reachable,", part1(input))));
+        while !explorer.run("step", &[]).unwrap().starts_with("nothing") {}
+        assert_eq!(explorer.removed, part2(input));
+        assert_eq!(
+            explorer.run("show", &[]).unwrap(),
+            GridDisplay(&frames(input).last().unwrap().grid)
+                .to_string()
+                .trim_end()
+        );
+    }
+
+    #[test]
+    fn test_animation_is_deterministic() {
+        let input = include_str!("../../inputs/2025/day04_test.txt");
+        let gif = animation(input, 4, 20);
+        assert_eq!(gif, animation(input, 4, 20));
+        assert_eq!(crate::utils::fnv1a(&gif), 3008542355039840882);
+    }
+}
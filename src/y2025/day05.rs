@@ -0,0 +1,347 @@
+use crate::online::Online;
+use crate::registry::Answer;
+use crate::repl::{Session, arg};
+use crate::stream::Records;
+use std::cmp;
+use std::io::{self, BufRead};
+use std::ops::RangeInclusive;
+
+/// Sorts `ranges` and merges the overlapping ones into sorted, disjoint ranges.
+/// Empty ranges such as `5-3` are dropped.
+pub fn merge_ranges(ranges: &mut [RangeInclusive<i64>]) -> Vec<RangeInclusive<i64>> {
+    ranges.sort_unstable_by_key(|r| *r.start());
+
+    let mut merged: Vec<RangeInclusive<i64>> = Vec::new();
+
+    for range in ranges.iter().filter(|r| !r.is_empty()) {
+        match merged.last_mut() {
+            Some(current) if range.start() <= current.end() => {
+                *current = *current.start()..=cmp::max(*current.end(), *range.end());
+            }
+            _ => merged.push(range.clone()),
+        }
+    }
+
+    merged
+}
+
+/// Total number of IDs covered. Unsigned, as a single range like
+/// `0-9223372036854775807` already holds more IDs than fit in an `i64`.
+pub fn merge_and_sum_ranges(ranges: &mut [RangeInclusive<i64>]) -> u64 {
+    merge_ranges(ranges)
+        .iter()
+        .map(|range| range.end().abs_diff(*range.start()) + 1)
+        .sum()
+}
+
+/// Sorted, disjoint ranges of IDs that answer membership by binary search.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    ranges: Vec<RangeInclusive<i64>>,
+}
+
+impl IntervalSet {
+    pub fn new(mut ranges: Vec<RangeInclusive<i64>>) -> Self {
+        IntervalSet {
+            ranges: merge_ranges(&mut ranges),
+        }
+    }
+
+    pub fn contains(&self, id: i64) -> bool {
+        self.find(id).is_some()
+    }
+
+    /// The range holding `id`, if any.
+    pub fn find(&self, id: i64) -> Option<&RangeInclusive<i64>> {
+        let i = self.ranges.partition_point(|range| *range.end() < id);
+        self.ranges.get(i).filter(|range| range.contains(&id))
+    }
+
+    /// The IDs between the ranges, as ranges.
+    pub fn gaps(&self) -> Vec<RangeInclusive<i64>> {
+        self.ranges
+            .windows(2)
+            .filter(|pair| pair[0].end() + 1 < *pair[1].start())
+            .map(|pair| pair[0].end() + 1..=pair[1].start() - 1)
+            .collect()
+    }
+
+    /// Number of IDs covered.
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|range| range.end().abs_diff(*range.start()) + 1)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn ranges(&self) -> &[RangeInclusive<i64>] {
+        &self.ranges
+    }
+}
+
+pub fn parse_ranges(input: &str) -> Vec<RangeInclusive<i64>> {
+    input
+        .lines()
+        .filter_map(|line| {
+            let (start, end) = line.split_once('-')?;
+            Some(start.parse::<i64>().ok()?..=end.parse::<i64>().ok()?)
+        })
+        .collect()
+}
+
+pub fn part1(input: &str) -> i64 {
+    let (ranges_block, ids_block) = input.split_once("\n\n").unwrap_or((input, ""));
+
+    let ranges = parse_ranges(ranges_block);
+
+    ids_block
+        .lines()
+        .filter_map(|line| line.parse::<i64>().ok())
+        .filter(|id| ranges.iter().any(|r| r.contains(id)))
+        .count() as i64
+}
+
+pub fn part2(input: &str) -> u64 {
+    let (ranges_block, _) = input.split_once("\n\n").unwrap_or((input, ""));
+    let mut ranges = parse_ranges(ranges_block);
+    merge_and_sum_ranges(&mut ranges)
+}
+
+/// Reads the ranges from `lines` up to the blank line that ends them.
+fn read_ranges<R: BufRead>(lines: &mut Records<R>) -> io::Result<Vec<RangeInclusive<i64>>> {
+    let mut ranges = Vec::new();
+    while let Some(line) = lines.next_record()? {
+        if line.is_empty() {
+            break;
+        }
+        ranges.extend(parse_ranges(line));
+    }
+    Ok(ranges)
+}
+
+/// [`part1`] reading the IDs one line at a time from `reader`. Only the
+/// merged ranges are kept in memory, and each ID is looked up by binary
+/// search.
+pub fn part1_stream(reader: impl BufRead) -> io::Result<i64> {
+    let mut lines = Records::lines(reader);
+    let fresh_ids = IntervalSet::new(read_ranges(&mut lines)?);
+
+    let mut fresh = 0;
+    while let Some(line) = lines.next_record()? {
+        if line.parse().is_ok_and(|id| fresh_ids.contains(id)) {
+            fresh += 1;
+        }
+    }
+    Ok(fresh)
+}
+
+/// The database read a line at a time: ranges until the blank line, then
+/// IDs, each counted as it arrives against the ranges merged so far.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Inventory {
+    ranges: Vec<RangeInclusive<i64>>,
+    /// Built from `ranges` at the blank line.
+    fresh_ids: Option<IntervalSet>,
+    fresh: i64,
+}
+
+impl Online for Inventory {
+    fn push_line(&mut self, line: &str) -> Result<(), String> {
+        match &self.fresh_ids {
+            None if line.is_empty() => {
+                self.fresh_ids = Some(IntervalSet::new(self.ranges.clone()));
+            }
+            None => match parse_ranges(line).pop() {
+                Some(range) => self.ranges.push(range),
+                None => return Err(format!("expected a range like 3-5, got {:?}", line)),
+            },
+            Some(_) if line.is_empty() => {}
+            Some(fresh_ids) => {
+                let id: i64 = line
+                    .parse()
+                    .map_err(|_| format!("expected an ID, got {:?}", line))?;
+                self.fresh += fresh_ids.contains(id) as i64;
+            }
+        }
+        Ok(())
+    }
+
+    fn current_answer(&self, part: u32) -> Option<Answer> {
+        match part {
+            1 => Some(Answer::from(self.fresh)),
+            2 => Some(Answer::from(match &self.fresh_ids {
+                Some(fresh_ids) => fresh_ids.len(),
+                None => IntervalSet::new(self.ranges.clone()).len(),
+            })),
+            _ => None,
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Inventory::default();
+    }
+}
+
+/// [`part2`] reading only the ranges from `reader`.
+pub fn part2_stream(reader: impl BufRead) -> io::Result<u64> {
+    let mut ranges = read_ranges(&mut Records::lines(reader))?;
+    Ok(merge_and_sum_ranges(&mut ranges))
+}
+
+/// The parsed database, for asking about single IDs.
+pub struct Explorer {
+    fresh_ids: IntervalSet,
+    ids: Vec<i64>,
+}
+
+impl Explorer {
+    pub fn new(input: &str) -> Self {
+        let (ranges_block, ids_block) = input.split_once("\n\n").unwrap_or((input, ""));
+        Explorer {
+            fresh_ids: IntervalSet::new(parse_ranges(ranges_block)),
+            ids: ids_block
+                .lines()
+                .filter_map(|line| line.parse().ok())
+                .collect(),
+        }
+    }
+}
+
+impl Session for Explorer {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            (
+                "contains X",
+                "whether ID X is fresh, and which range holds it",
+            ),
+            (
+                "gaps",
+                "the IDs no range covers, between the first and last range",
+            ),
+            ("total", "how many IDs the ranges cover"),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "contains" => {
+                let id: i64 = arg(args, 0, "X")?;
+                Ok(match self.fresh_ids.find(id) {
+                    Some(range) => format!("{} is fresh, in {}-{}", id, range.start(), range.end()),
+                    None => format!("{} is spoiled", id),
+                })
+            }
+            "gaps" => {
+                let gaps = self.fresh_ids.gaps();
+                if gaps.is_empty() {
+                    return Ok("no gaps".to_string());
+                }
+                let lines: Vec<String> = gaps
+                    .iter()
+                    .map(|gap| format!("{}-{}", gap.start(), gap.end()))
+                    .collect();
+                Ok(lines.join("\n"))
+            }
+            "total" => {
+                let fresh = self
+                    .ids
+                    .iter()
+                    .filter(|&&id| self.fresh_ids.contains(id))
+                    .count();
+                Ok(format!(
+                    "{} IDs in {} merged ranges; {} of {} listed IDs are fresh",
+                    self.fresh_ids.len(),
+                    self.fresh_ids.ranges().len(),
+                    fresh,
+                    self.ids.len()
+                ))
+            }
+            _ => Err(format!("unknown command {:?}", command)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prop::{for_all, shrink_i64, shrink_vec, vec_of};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_merge_ranges_properties() {
+        for_all(|rng, size| {
+            vec_of(rng, size, |rng| {
+                let start = rng.between(0, 60);
+                start..=start + rng.between(-1, 10)
+            })
+        })
+        .shrink_with(|ranges| {
+            shrink_vec(ranges, |r| {
+                shrink_i64(*r.start())
+                    .into_iter()
+                    .filter(|&start| start <= *r.end())
+                    .map(|start| start..=*r.end())
+                    .collect()
+            })
+        })
+        .check(|ranges| {
+            let union: HashSet<i64> = ranges.iter().flat_map(|r| r.clone()).collect();
+            let merged = merge_ranges(&mut ranges.clone());
+
+            merged.iter().all(|r| !r.is_empty())
+                && merged.windows(2).all(|w| w[0].end() < w[1].start())
+                && merged.iter().flat_map(|r| r.clone()).count() == union.len()
+                && merge_and_sum_ranges(&mut ranges.clone()) == union.len() as u64
+        });
+    }
+
+    #[test]
+    fn test_interval_set() {
+        let set = IntervalSet::new(parse_ranges(
+            "10-14
+3-5
+12-18
+16-20",
+        ));
+        assert_eq!(set.ranges(), [3..=5, 10..=20]);
+        assert_eq!(set.len(), 14);
+        assert!(set.contains(3) && set.contains(17) && !set.contains(9));
+        assert!(IntervalSet::new(Vec::new()).is_empty());
+        assert_eq!(set.find(12), Some(&(10..=20)));
+        assert_eq!(set.gaps(), [6..=9]);
+    }
+
+    #[test]
+    fn test_explorer() {
+        let mut explorer = Explorer::new(include_str!("../../inputs/2025/day05_test.txt"));
+        assert_eq!(
+            explorer.run("contains", &["5"]).unwrap(),
+            "5 is fresh, in 3-5"
+        );
+        assert_eq!(explorer.run("contains", &["8"]).unwrap(), "8 is spoiled");
+        assert_eq!(explorer.run("gaps", &[]).unwrap(), "6-9");
+        assert_eq!(
+            explorer.run("total", &[]).unwrap(),
+            "14 IDs in 2 merged ranges; 3 of 6 listed IDs are fresh"
+        );
+    }
+
+    #[test]
+    fn test_stream_skips_malformed_lines_like_in_memory() {
+        let input = "3-5\n10-x\n12-18\n\n5\nx\n17\n";
+        assert_eq!(part1_stream(input.as_bytes()).unwrap(), part1(input));
+        assert_eq!(part2_stream(input.as_bytes()).unwrap(), part2(input));
+        assert_eq!(part1(input), 2);
+        assert_eq!(part2(input), 10);
+    }
+
+    #[test]
+    fn test_reversed_range_is_empty() {
+        let mut ranges = parse_ranges("10-5\n6-8");
+        assert_eq!(merge_and_sum_ranges(&mut ranges), 3);
+    }
+}
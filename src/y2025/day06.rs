@@ -0,0 +1,743 @@
+use crate::bigint::BigInt;
+use crate::explain::Trace;
+use crate::utils::Columns;
+use std::fmt;
+use std::num::{IntErrorKind, ParseIntError};
+use std::str::FromStr;
+
+/// How the numbers of a problem are laid out inside its block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reading {
+    /// Every row of the block holds one number.
+    Rows,
+    /// Every column of the block holds one number, read top to bottom,
+    /// with the columns taken right to left.
+    Columns,
+}
+
+/// What a cell of a digit row holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cells {
+    /// Plain non-negative numbers.
+    Numbers,
+    /// Infix expressions over `+ - * /` and parentheses, without spaces.
+    Expressions,
+}
+
+/// Operator symbols: `+`, `*`, `-`, `/`, `<` (min), `>` (max) and `|` (concat).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Multiply,
+    Subtract,
+    Divide,
+    Min,
+    Max,
+    Concat,
+}
+
+impl Operator {
+    pub fn symbol(self) -> char {
+        match self {
+            Operator::Add => '+',
+            Operator::Multiply => '*',
+            Operator::Subtract => '-',
+            Operator::Divide => '/',
+            Operator::Min => '<',
+            Operator::Max => '>',
+            Operator::Concat => '|',
+        }
+    }
+
+    pub fn from_symbol(c: char) -> Option<Self> {
+        match c {
+            '+' => Some(Operator::Add),
+            '*' => Some(Operator::Multiply),
+            '-' => Some(Operator::Subtract),
+            '/' => Some(Operator::Divide),
+            '<' => Some(Operator::Min),
+            '>' => Some(Operator::Max),
+            '|' => Some(Operator::Concat),
+            _ => None,
+        }
+    }
+
+    /// Folds the operands left to right, so `-` and `/` take the first operand
+    /// as the starting value.
+    pub fn apply(self, operands: &[i64]) -> Result<i64, EvalError> {
+        let (&first, rest) = match self {
+            Operator::Add if operands.is_empty() => return Ok(0),
+            Operator::Multiply if operands.is_empty() => return Ok(1),
+            _ => operands.split_first().ok_or(EvalError::NoOperands)?,
+        };
+
+        rest.iter().try_fold(first, |acc, &n| match self {
+            Operator::Add => acc.checked_add(n).ok_or(EvalError::Overflow),
+            Operator::Multiply => acc.checked_mul(n).ok_or(EvalError::Overflow),
+            Operator::Subtract => acc.checked_sub(n).ok_or(EvalError::Overflow),
+            Operator::Divide => divide(acc, n),
+            Operator::Min => Ok(acc.min(n)),
+            Operator::Max => Ok(acc.max(n)),
+            Operator::Concat => concat(acc, n),
+        })
+    }
+}
+
+impl Operator {
+    /// [`Operator::apply`] in exact arithmetic, where only dividing by zero
+    /// and concatenating a negative number fail.
+    pub fn apply_exact(self, operands: &[BigInt]) -> Result<BigInt, EvalError> {
+        let (first, rest) = match self {
+            Operator::Add if operands.is_empty() => return Ok(BigInt::zero()),
+            Operator::Multiply if operands.is_empty() => return Ok(BigInt::one()),
+            _ => operands.split_first().ok_or(EvalError::NoOperands)?,
+        };
+        rest.iter().try_fold(first.clone(), |acc, n| match self {
+            Operator::Add => Ok(acc + n),
+            Operator::Multiply => Ok(acc * n),
+            Operator::Subtract => Ok(acc - n),
+            Operator::Divide if n.is_zero() => Err(EvalError::DivisionByZero),
+            Operator::Divide => Ok(acc / n),
+            Operator::Min => Ok(acc.min(n.clone())),
+            Operator::Max => Ok(acc.max(n.clone())),
+            Operator::Concat if n.is_negative() => Err(EvalError::NegativeConcat),
+            Operator::Concat => {
+                let shift = BigInt::from(10i64).pow(n.to_string().len() as u32);
+                Ok(acc * shift + n)
+            }
+        })
+    }
+}
+
+/// A plain number cell. Only a value out of range is an overflow; anything
+/// else is a syntax error at the first byte that can't be part of a number.
+fn parse_number(cell: &str) -> Result<i64, EvalError> {
+    cell.parse().map_err(|e: ParseIntError| match e.kind() {
+        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => EvalError::Overflow,
+        _ => number_syntax_error(cell),
+    })
+}
+
+fn number_syntax_error(cell: &str) -> EvalError {
+    let sign = cell.starts_with(['+', '-']) as usize;
+    let position = cell
+        .bytes()
+        .skip(sign)
+        .position(|b| !b.is_ascii_digit())
+        .map_or(cell.len(), |p| p + sign);
+    EvalError::Syntax { position }
+}
+
+fn divide(a: i64, b: i64) -> Result<i64, EvalError> {
+    if b == 0 {
+        return Err(EvalError::DivisionByZero);
+    }
+    a.checked_div(b).ok_or(EvalError::Overflow)
+}
+
+fn concat(a: i64, b: i64) -> Result<i64, EvalError> {
+    if b < 0 {
+        return Err(EvalError::NegativeConcat);
+    }
+    let shift = 10i64
+        .checked_pow(b.checked_ilog10().unwrap_or(0) + 1)
+        .ok_or(EvalError::Overflow)?;
+    a.checked_mul(shift)
+        .and_then(|n| n.checked_add(b))
+        .ok_or(EvalError::Overflow)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvalError {
+    Overflow,
+    DivisionByZero,
+    NegativeConcat,
+    NoOperands,
+    Syntax { position: usize },
+    TooDeep,
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::Overflow => write!(f, "arithmetic overflow"),
+            EvalError::DivisionByZero => write!(f, "division by zero"),
+            EvalError::NegativeConcat => write!(f, "cannot concatenate a negative number"),
+            EvalError::NoOperands => write!(f, "operator needs at least one operand"),
+            EvalError::Syntax { position } => write!(f, "syntax error at offset {position}"),
+            EvalError::TooDeep => write!(f, "expression is nested too deeply"),
+        }
+    }
+}
+
+/// Parentheses and unary minus both recurse, so nesting is capped to keep
+/// hostile cells from overflowing the stack.
+const MAX_NESTING: usize = 256;
+
+/// Recursive descent evaluator for a single expression cell.
+pub struct Expression<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> Expression<'a> {
+    pub fn eval(s: &'a str) -> Result<i64, EvalError> {
+        let mut expr = Expression {
+            bytes: s.as_bytes(),
+            pos: 0,
+            depth: 0,
+        };
+        let value = expr.sum()?;
+        if expr.pos != expr.bytes.len() {
+            return Err(EvalError::Syntax { position: expr.pos });
+        }
+        Ok(value)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn sum(&mut self) -> Result<i64, EvalError> {
+        let mut acc = self.product()?;
+        loop {
+            match self.peek() {
+                Some(b'+') => {
+                    self.pos += 1;
+                    acc = Operator::Add.apply(&[acc, self.product()?])?;
+                }
+                Some(b'-') => {
+                    self.pos += 1;
+                    acc = Operator::Subtract.apply(&[acc, self.product()?])?;
+                }
+                _ => return Ok(acc),
+            }
+        }
+    }
+
+    fn product(&mut self) -> Result<i64, EvalError> {
+        let mut acc = self.factor()?;
+        loop {
+            match self.peek() {
+                Some(b'*') => {
+                    self.pos += 1;
+                    acc = Operator::Multiply.apply(&[acc, self.factor()?])?;
+                }
+                Some(b'/') => {
+                    self.pos += 1;
+                    acc = Operator::Divide.apply(&[acc, self.factor()?])?;
+                }
+                _ => return Ok(acc),
+            }
+        }
+    }
+
+    fn factor(&mut self) -> Result<i64, EvalError> {
+        if self.depth == MAX_NESTING {
+            return Err(EvalError::TooDeep);
+        }
+        self.depth += 1;
+        let value = self.atom();
+        self.depth -= 1;
+        value
+    }
+
+    fn atom(&mut self) -> Result<i64, EvalError> {
+        match self.peek() {
+            Some(b'-') => {
+                self.pos += 1;
+                self.factor()?.checked_neg().ok_or(EvalError::Overflow)
+            }
+            Some(b'(') => {
+                self.pos += 1;
+                let value = self.sum()?;
+                if self.peek() != Some(b')') {
+                    return Err(EvalError::Syntax { position: self.pos });
+                }
+                self.pos += 1;
+                Ok(value)
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let mut value: i64 = 0;
+                while let Some(c) = self.peek().filter(u8::is_ascii_digit) {
+                    value = value
+                        .checked_mul(10)
+                        .and_then(|v| v.checked_add((c - b'0') as i64))
+                        .ok_or(EvalError::Overflow)?;
+                    self.pos += 1;
+                }
+                Ok(value)
+            }
+            _ => Err(EvalError::Syntax { position: self.pos }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorksheetError {
+    Empty,
+    InvalidCharacter {
+        row: usize,
+        column: usize,
+        found: char,
+    },
+    MissingOperator {
+        column: usize,
+    },
+    MultipleOperators {
+        first: usize,
+        second: usize,
+    },
+    UnknownOperator {
+        column: usize,
+        found: char,
+    },
+    Eval {
+        column: usize,
+        error: EvalError,
+    },
+}
+
+impl fmt::Display for WorksheetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WorksheetError::Empty => write!(f, "worksheet is empty"),
+            WorksheetError::InvalidCharacter { row, column, found } => {
+                write!(f, "unexpected {found:?} at row {row}, column {column}")
+            }
+            WorksheetError::MissingOperator { column } => {
+                write!(f, "problem starting at column {column} has no operator")
+            }
+            WorksheetError::MultipleOperators { first, second } => {
+                write!(f, "problem has operators at columns {first} and {second}")
+            }
+            WorksheetError::UnknownOperator { column, found } => {
+                write!(f, "unknown operator {found:?} at column {column}")
+            }
+            WorksheetError::Eval { column, error } => {
+                write!(f, "problem starting at column {column}: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WorksheetError {}
+
+/// A single problem: the columns it spans and its operator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    start: usize,
+    end: usize,
+    operator: Operator,
+}
+
+/// One problem of the worksheet, with its operands as `i64` or, for exact
+/// results, as [`BigInt`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem<N = i64> {
+    column: usize,
+    operator: Operator,
+    operands: Vec<N>,
+}
+
+impl Problem {
+    pub fn solve(&self) -> Result<i64, WorksheetError> {
+        self.operator
+            .apply(&self.operands)
+            .map_err(|error| WorksheetError::Eval {
+                column: self.column,
+                error,
+            })
+    }
+}
+
+impl Problem<BigInt> {
+    pub fn solve(&self) -> Result<BigInt, WorksheetError> {
+        self.operator
+            .apply_exact(&self.operands)
+            .map_err(|error| WorksheetError::Eval {
+                column: self.column,
+                error,
+            })
+    }
+}
+
+/// The digit rows of the worksheet, padded with spaces to a common width, and
+/// the problem blocks found between fully blank columns.
+#[derive(Debug)]
+pub struct Worksheet {
+    rows: Vec<Vec<char>>,
+    blocks: Vec<Block>,
+    cells: Cells,
+}
+
+impl FromStr for Worksheet {
+    type Err = WorksheetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Worksheet::parse(s, Cells::Numbers)
+    }
+}
+
+impl Worksheet {
+    pub fn parse(s: &str, cells: Cells) -> Result<Self, WorksheetError> {
+        let lines: Vec<&str> = s.lines().collect();
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .ok_or(WorksheetError::Empty)?;
+
+        let mut grid: Vec<Vec<char>> = lines
+            .iter()
+            .map(|line| {
+                let mut row: Vec<char> = line.chars().collect();
+                row.resize(width, ' ');
+                row
+            })
+            .collect();
+        let operators = grid.pop().ok_or(WorksheetError::Empty)?;
+
+        for (y, row) in grid.iter().enumerate() {
+            if let Some((x, &c)) = row
+                .iter()
+                .enumerate()
+                .find(|&(_, &c)| !Self::is_cell_char(cells, c))
+            {
+                return Err(WorksheetError::InvalidCharacter {
+                    row: y,
+                    column: x,
+                    found: c,
+                });
+            }
+        }
+
+        let blank: Vec<bool> = Columns::padded(grid.iter().chain([&operators]), &' ')
+            .map(|column| column.into_iter().all(|c| *c == ' '))
+            .collect();
+
+        let mut blocks = Vec::new();
+        let mut x = 0;
+        while x < width {
+            if blank[x] {
+                x += 1;
+                continue;
+            }
+            let start = x;
+            while x < width && !blank[x] {
+                x += 1;
+            }
+            blocks.push(Self::block(&operators, start, x)?);
+        }
+
+        Ok(Worksheet {
+            rows: grid,
+            blocks,
+            cells,
+        })
+    }
+
+    fn is_cell_char(cells: Cells, c: char) -> bool {
+        match cells {
+            Cells::Numbers => c.is_ascii_digit() || c == ' ',
+            Cells::Expressions => c.is_ascii_digit() || " +-*/()".contains(c),
+        }
+    }
+
+    fn block(operators: &[char], start: usize, end: usize) -> Result<Block, WorksheetError> {
+        let mut found: Option<(usize, char)> = None;
+        for (x, &c) in operators.iter().enumerate().take(end).skip(start) {
+            if c == ' ' {
+                continue;
+            }
+            if let Some((first, _)) = found {
+                return Err(WorksheetError::MultipleOperators { first, second: x });
+            }
+            found = Some((x, c));
+        }
+
+        let (column, symbol) = found.ok_or(WorksheetError::MissingOperator { column: start })?;
+        let operator = Operator::from_symbol(symbol).ok_or(WorksheetError::UnknownOperator {
+            column,
+            found: symbol,
+        })?;
+        Ok(Block {
+            start,
+            end,
+            operator,
+        })
+    }
+
+    pub fn problems(&self, reading: Reading) -> Result<Vec<Problem>, WorksheetError> {
+        self.problems_with(reading, |n| match self.cells {
+            Cells::Numbers => parse_number(n),
+            Cells::Expressions => Expression::eval(n),
+        })
+    }
+
+    /// [`Worksheet::problems`] with exact operands. Expressions are still
+    /// evaluated in `i64`.
+    pub fn problems_exact(&self, reading: Reading) -> Result<Vec<Problem<BigInt>>, WorksheetError> {
+        self.problems_with(reading, |n| match self.cells {
+            Cells::Numbers => n.parse::<BigInt>().map_err(|_| number_syntax_error(n)),
+            Cells::Expressions => Expression::eval(n).map(BigInt::from),
+        })
+    }
+
+    fn problems_with<N>(
+        &self,
+        reading: Reading,
+        parse: impl Fn(&str) -> Result<N, EvalError>,
+    ) -> Result<Vec<Problem<N>>, WorksheetError> {
+        self.blocks
+            .iter()
+            .map(|block| {
+                let cells: Vec<&[char]> = self
+                    .rows
+                    .iter()
+                    .map(|row| &row[block.start..block.end])
+                    .collect();
+
+                let numbers: Vec<String> = match reading {
+                    Reading::Rows => cells.iter().map(|row| row.iter().collect()).collect(),
+                    Reading::Columns => {
+                        let mut columns: Vec<String> = Columns::padded(cells.iter().copied(), &' ')
+                            .map(|column| column.into_iter().collect())
+                            .collect();
+                        columns.reverse();
+                        columns
+                    }
+                };
+
+                let operands = numbers
+                    .iter()
+                    .map(|n| n.trim())
+                    .filter(|n| !n.is_empty())
+                    .map(|n| {
+                        parse(n).map_err(|error| WorksheetError::Eval {
+                            column: block.start,
+                            error,
+                        })
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                Ok(Problem {
+                    column: block.start,
+                    operator: block.operator,
+                    operands,
+                })
+            })
+            .collect()
+    }
+}
+
+pub fn solve(input: &str, reading: Reading, cells: Cells) -> Result<i64, WorksheetError> {
+    solve_traced(input, reading, cells, &mut Trace::off())
+}
+
+/// [`solve`], noting every problem's operands, operator and result.
+pub fn solve_traced(
+    input: &str,
+    reading: Reading,
+    cells: Cells,
+    trace: &mut Trace,
+) -> Result<i64, WorksheetError> {
+    Worksheet::parse(input, cells)?
+        .problems(reading)?
+        .iter()
+        .try_fold(0i64, |total, problem| {
+            let value = problem.solve()?;
+            trace.note(|| {
+                let operands: Vec<String> = problem.operands.iter().map(i64::to_string).collect();
+                let operator = format!(" {} ", problem.operator.symbol());
+                format!(
+                    "column {}: {} = {}",
+                    problem.column,
+                    operands.join(&operator),
+                    value
+                )
+            });
+            total.checked_add(value).ok_or(WorksheetError::Eval {
+                column: problem.column,
+                error: EvalError::Overflow,
+            })
+        })
+}
+
+/// [`solve`] in exact arithmetic, for worksheets whose numbers or results
+/// don't fit an `i64`.
+pub fn solve_exact(input: &str, reading: Reading, cells: Cells) -> Result<BigInt, WorksheetError> {
+    Worksheet::parse(input, cells)?
+        .problems_exact(reading)?
+        .iter()
+        .map(Problem::<BigInt>::solve)
+        .sum()
+}
+
+pub fn part1_with(input: &str, cells: Cells) -> i64 {
+    solve(input, Reading::Rows, cells).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn part2_with(input: &str, cells: Cells) -> i64 {
+    solve(input, Reading::Columns, cells).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn part1(input: &str) -> i64 {
+    part1_with(input, Cells::Numbers)
+}
+
+pub fn part2(input: &str) -> i64 {
+    part2_with(input, Cells::Numbers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ragged_lines() {
+        // Trailing spaces trimmed from every line but the operators.
+        let input = "123 328  51 64\n 45 64  387 23\n  6 98  215 314\n*   +   *   +  \n";
+        assert_eq!(part1(input), 4277556);
+        assert_eq!(part2(input), 3263827);
+    }
+
+    #[test]
+    fn test_worksheet_errors() {
+        assert_eq!(
+            "12 34\n+    \n".parse::<Worksheet>().unwrap_err(),
+            WorksheetError::MissingOperator { column: 3 }
+        );
+        assert_eq!(
+            "1234\n+ * \n".parse::<Worksheet>().unwrap_err(),
+            WorksheetError::MultipleOperators {
+                first: 0,
+                second: 2
+            }
+        );
+        assert_eq!(
+            "1x 3\n+  *\n".parse::<Worksheet>().unwrap_err(),
+            WorksheetError::InvalidCharacter {
+                row: 0,
+                column: 1,
+                found: 'x'
+            }
+        );
+    }
+
+    #[test]
+    fn test_exact() {
+        let inputs = [
+            include_str!("../../inputs/2025/day06_test.txt"),
+            "20 20 20 2  20\n 5  5  5 5   3\n-  /  <  >  | \n",
+            "-7+2  3   \n2     -4  \n/     |   \n",
+        ];
+        for input in inputs {
+            for cells in [Cells::Numbers, Cells::Expressions] {
+                for reading in [Reading::Rows, Reading::Columns] {
+                    assert_eq!(
+                        solve_exact(input, reading, cells).map(|n| n.to_i128()),
+                        solve(input, reading, cells).map(|n| Some(n as i128))
+                    );
+                }
+            }
+        }
+
+        for symbol in "+*-/<>|".chars() {
+            let operator = Operator::from_symbol(symbol).unwrap();
+            for operands in [&[][..], &[7], &[12, 5, 3], &[-12, 0, 4]] {
+                let exact: Vec<BigInt> = operands.iter().map(|&n| BigInt::from(n)).collect();
+                assert_eq!(
+                    operator.apply_exact(&exact),
+                    operator.apply(operands).map(BigInt::from),
+                    "{} {:?}",
+                    symbol,
+                    operands
+                );
+            }
+        }
+
+        let input = "99999999999\n99999999999\n*          \n";
+        assert!(solve(input, Reading::Rows, Cells::Numbers).is_err());
+        assert_eq!(
+            solve_exact(input, Reading::Rows, Cells::Numbers)
+                .unwrap()
+                .to_string(),
+            "9999999999800000000001"
+        );
+    }
+
+    #[test]
+    fn test_operators() {
+        let input = "20 20 20 2  20\n 5  5  5 5   3\n-  /  <  >  | \n";
+        assert_eq!(part1(input), 15 + 4 + 5 + 5 + 203);
+        assert_eq!(
+            "12\n% \n".parse::<Worksheet>().unwrap_err(),
+            WorksheetError::UnknownOperator {
+                column: 0,
+                found: '%'
+            }
+        );
+        assert_eq!(
+            solve("9\n0\n/\n", Reading::Rows, Cells::Numbers),
+            Err(WorksheetError::Eval {
+                column: 0,
+                error: EvalError::DivisionByZero
+            })
+        );
+    }
+
+    #[test]
+    fn test_expressions() {
+        let input = "2+3*4     (2+3)*4\n-10/3     7       \n+         *       \n";
+        assert_eq!(part1_with(input, Cells::Expressions), 14 - 3 + 20 * 7);
+        assert_eq!(
+            Expression::eval("9223372036854775807+1"),
+            Err(EvalError::Overflow)
+        );
+        assert_eq!(
+            Expression::eval("(1+2"),
+            Err(EvalError::Syntax { position: 4 })
+        );
+    }
+
+    #[test]
+    fn test_number_cells() {
+        assert_eq!(parse_number("1a"), Err(EvalError::Syntax { position: 1 }));
+        assert_eq!(parse_number("--3"), Err(EvalError::Syntax { position: 1 }));
+        assert_eq!(parse_number("-"), Err(EvalError::Syntax { position: 1 }));
+        assert_eq!(
+            parse_number("-9223372036854775809"),
+            Err(EvalError::Overflow)
+        );
+        assert_eq!(
+            solve("1 2\n 3 \n+  \n", Reading::Rows, Cells::Numbers),
+            Err(WorksheetError::Eval {
+                column: 0,
+                error: EvalError::Syntax { position: 1 }
+            })
+        );
+        assert_eq!(
+            solve("99999999999999999999\n+\n", Reading::Rows, Cells::Numbers),
+            Err(WorksheetError::Eval {
+                column: 0,
+                error: EvalError::Overflow
+            })
+        );
+    }
+
+    #[test]
+    fn test_deeply_nested_expression() {
+        let nested = format!("{}1{}", "(".repeat(100_000), ")".repeat(100_000));
+        assert_eq!(Expression::eval(&nested), Err(EvalError::TooDeep));
+        let negated = format!("{}1", "-".repeat(100_000));
+        assert_eq!(Expression::eval(&negated), Err(EvalError::TooDeep));
+        let nest = |depth| format!("{}1{}", "(".repeat(depth), ")".repeat(depth));
+        assert_eq!(Expression::eval(&nest(MAX_NESTING - 1)), Ok(1));
+        assert_eq!(
+            Expression::eval(&nest(MAX_NESTING)),
+            Err(EvalError::TooDeep)
+        );
+    }
+}
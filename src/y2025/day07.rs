@@ -0,0 +1,637 @@
+use crate::count::{Count, Overflow};
+use crate::image::{CellColors, Indexed, Rgb};
+use crate::online::Online;
+use crate::registry::Answer;
+use crate::rng::Rng;
+use crate::viz::Frame;
+use std::collections::BTreeMap;
+use std::fmt;
+
+pub const DEFAULT_SPLITTERS: &[u8] = b"^";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManifoldError {
+    Empty,
+    NoStart,
+    UnexpectedByte {
+        row: usize,
+        column: usize,
+        found: u8,
+    },
+}
+
+impl fmt::Display for ManifoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ManifoldError::Empty => write!(f, "manifold is empty"),
+            ManifoldError::NoStart => write!(f, "first row has no start position 'S'"),
+            ManifoldError::UnexpectedByte { row, column, found } => write!(
+                f,
+                "unexpected {:?} at row {row}, column {column}",
+                *found as char
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ManifoldError {}
+
+/// Checks that row `y` holds only empty space and splitters, besides the
+/// start position if it has one.
+fn check_row(
+    row: &[u8],
+    y: usize,
+    start: Option<usize>,
+    splitters: &[u8],
+) -> Result<(), ManifoldError> {
+    for (x, &b) in row.iter().enumerate() {
+        if b != b'.' && !splitters.contains(&b) && Some(x) != start {
+            return Err(ManifoldError::UnexpectedByte {
+                row: y,
+                column: x,
+                found: b,
+            });
+        }
+    }
+    Ok(())
+}
+
+pub struct Manifold<'a> {
+    rows: Vec<&'a [u8]>,
+    start: usize,
+    splitters: &'a [u8],
+}
+
+/// Beams leaving every row of the manifold, with how many timelines put a
+/// beam in each column. Row 0 only holds the start position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BeamField<C> {
+    pub rows: Vec<BTreeMap<usize, C>>,
+    pub splits: usize,
+    pub exited: C,
+}
+
+impl<C: Count> BeamField<C> {
+    pub fn timelines(&self) -> Result<C, Overflow> {
+        let mut total = self.exited.clone();
+        for count in self.rows.last().into_iter().flat_map(BTreeMap::values) {
+            total.try_add_assign(count)?;
+        }
+        Ok(total)
+    }
+}
+
+impl<'a> Manifold<'a> {
+    pub fn parse(input: &'a str, splitters: &'a [u8]) -> Result<Self, ManifoldError> {
+        let rows: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+        let first = rows.first().ok_or(ManifoldError::Empty)?;
+        let start = first
+            .iter()
+            .position(|&b| b == b'S')
+            .ok_or(ManifoldError::NoStart)?;
+
+        for (y, row) in rows.iter().enumerate() {
+            check_row(row, y, (y == 0).then_some(start), splitters)?;
+        }
+
+        Ok(Manifold {
+            rows,
+            start,
+            splitters,
+        })
+    }
+
+    /// A manifold of just `row`, the `y`th of a larger one, for stepping
+    /// beams through it a row at a time. It has no start of its own.
+    fn single_row(row: &'a [u8], y: usize, splitters: &'a [u8]) -> Result<Self, ManifoldError> {
+        check_row(row, y, None, splitters)?;
+        Ok(Manifold {
+            rows: vec![row],
+            start: 0,
+            splitters,
+        })
+    }
+
+    pub fn is_splitter(&self, row: usize, column: usize) -> bool {
+        self.rows[row]
+            .get(column)
+            .is_some_and(|b| self.splitters.contains(b))
+    }
+
+    /// Columns a beam at `column` occupies after passing through `row`.
+    /// `None` means that side of the split left the manifold.
+    pub fn step(&self, row: usize, column: usize) -> Vec<Option<usize>> {
+        if !self.is_splitter(row, column) {
+            return vec![Some(column)];
+        }
+        let right = column + 1;
+        vec![
+            column.checked_sub(1),
+            (right < self.rows[row].len()).then_some(right),
+        ]
+    }
+
+    pub fn simulate<C: Count>(&self) -> Result<BeamField<C>, Overflow> {
+        let mut field = BeamField {
+            rows: vec![BTreeMap::from([(self.start, C::one())])],
+            splits: 0,
+            exited: C::zero(),
+        };
+
+        for row in 1..self.rows.len() {
+            let mut next = BTreeMap::new();
+            for (&column, count) in field.rows.last().unwrap() {
+                if self.is_splitter(row, column) {
+                    field.splits += 1;
+                }
+                for target in self.step(row, column) {
+                    match target {
+                        Some(target) => next
+                            .entry(target)
+                            .or_insert_with(C::zero)
+                            .try_add_assign(count)?,
+                        None => field.exited.try_add_assign(count)?,
+                    }
+                }
+            }
+            field.rows.push(next);
+        }
+
+        Ok(field)
+    }
+
+    /// Where the beams go and how often they split, without counting
+    /// timelines, so nothing can overflow.
+    pub fn beams(&self) -> BeamField<()> {
+        let mut field = BeamField {
+            rows: vec![BTreeMap::from([(self.start, ())])],
+            splits: 0,
+            exited: (),
+        };
+
+        for row in 1..self.rows.len() {
+            let mut next = BTreeMap::new();
+            for &column in field.rows.last().unwrap().keys() {
+                if self.is_splitter(row, column) {
+                    field.splits += 1;
+                }
+                next.extend(
+                    self.step(row, column)
+                        .into_iter()
+                        .flatten()
+                        .map(|t| (t, ())),
+                );
+            }
+            field.rows.push(next);
+        }
+
+        field
+    }
+
+    /// Timelines counted top-down instead of row by row: a beam at `column`
+    /// leaving `row` splits into the timelines of the beams it becomes on the
+    /// next row, and a beam that leaves the manifold or reaches the bottom is
+    /// one timeline. The recursion goes one level deeper per row, so a tall
+    /// enough manifold overflows the stack; this only cross-checks the fold
+    /// in [`Self::simulate`], which handles any height.
+    #[cfg(test)]
+    fn timelines_memoized<C: Count>(
+        &self,
+        memo: &mut crate::memo::Memo<(usize, usize), Result<C, Overflow>>,
+    ) -> Result<C, Overflow> {
+        let count = |timelines: &mut dyn FnMut((usize, usize)) -> Result<C, Overflow>,
+                     (row, column): (usize, usize)| {
+            if row + 1 == self.rows.len() {
+                return Ok(C::one());
+            }
+            let mut total = C::zero();
+            for target in self.step(row + 1, column) {
+                match target {
+                    Some(target) => total.try_add_assign(&timelines((row + 1, target))?)?,
+                    None => total.try_add_assign(&C::one())?,
+                }
+            }
+            Ok(total)
+        };
+        memo.get((0, self.start), &count)
+    }
+
+    /// The manifold with every cell a beam passes through drawn as `|`.
+    pub fn render<C>(&self, field: &BeamField<C>) -> String {
+        let mut out = String::new();
+        for (y, row) in self.rows.iter().enumerate() {
+            for (x, &b) in row.iter().enumerate() {
+                let lit = field.rows[y].contains_key(&x);
+                out.push(if lit && b == b'.' { '|' } else { b as char });
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// The beams advancing one row per frame, drawn as in [`Self::render`].
+    pub fn frames<C>(&self, field: &BeamField<C>) -> Vec<Frame> {
+        let mut grid: Vec<Vec<char>> = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(|&b| b as char).collect())
+            .collect();
+        let mut frames = vec![Frame::new(grid.clone(), "row 0: 1 beam, 0 splits")];
+        let mut splits = 0;
+        for y in 1..self.rows.len() {
+            splits += field.rows[y - 1]
+                .keys()
+                .filter(|&&x| self.is_splitter(y, x))
+                .count();
+            // A beam past the end of a shorter row has nothing to light.
+            for &x in field.rows[y].keys() {
+                if let Some(cell) = grid[y].get_mut(x)
+                    && *cell == '.'
+                {
+                    *cell = '|';
+                }
+            }
+            let beams = field.rows[y].len();
+            let caption = format!("row {}: {} beams, {} splits", y, beams, splits);
+            frames.push(Frame::new(grid.clone(), caption));
+        }
+        frames
+    }
+
+    /// Number of timelines that continue from a beam at `column` after `row`,
+    /// for every beam in the field. Saturates rather than overflowing, which
+    /// only skews sampling on manifolds far beyond enumerating anyway.
+    fn completions<C>(&self, field: &BeamField<C>) -> Vec<BTreeMap<usize, u64>> {
+        let mut completions = vec![BTreeMap::new(); field.rows.len()];
+        for row in (0..field.rows.len()).rev() {
+            for &column in field.rows[row].keys() {
+                let count = if row + 1 == field.rows.len() {
+                    1
+                } else {
+                    self.step(row + 1, column)
+                        .into_iter()
+                        .map(|target| target.map_or(1, |t| completions[row + 1][&t]))
+                        .fold(0u64, u64::saturating_add)
+                };
+                completions[row].insert(column, count);
+            }
+        }
+        completions
+    }
+
+    /// The first `limit` timelines, as the beam column after every row. A
+    /// timeline that leaves the manifold ends early.
+    pub fn enumerate_timelines(&self, limit: usize) -> Vec<Vec<usize>> {
+        let mut timelines = Vec::new();
+        let mut stack = vec![(vec![self.start], false)];
+        while let Some((path, exited)) = stack.pop() {
+            if timelines.len() == limit {
+                break;
+            }
+            let row = path.len();
+            if exited || row == self.rows.len() {
+                timelines.push(path);
+                continue;
+            }
+            let column = *path.last().unwrap();
+            for target in self.step(row, column).into_iter().rev() {
+                let mut next = path.clone();
+                next.extend(target);
+                stack.push((next, target.is_none()));
+            }
+        }
+        timelines
+    }
+
+    /// A timeline drawn uniformly at random from all timelines.
+    pub fn sample_timeline<C>(&self, field: &BeamField<C>, rng: &mut Rng) -> Vec<usize> {
+        let completions = self.completions(field);
+        let mut path = vec![self.start];
+        while path.len() < self.rows.len() {
+            let row = path.len();
+            let column = *path.last().unwrap();
+            let targets = self.step(row, column);
+            let weights: Vec<u64> = targets
+                .iter()
+                .map(|target| target.map_or(1, |t| completions[row][&t]))
+                .collect();
+            let mut pick = rng.below(weights.iter().fold(0u64, |a, &w| a.saturating_add(w)));
+            let chosen = weights
+                .iter()
+                .position(|&w| {
+                    if pick < w {
+                        return true;
+                    }
+                    pick -= w;
+                    false
+                })
+                .unwrap();
+            match targets[chosen] {
+                Some(target) => path.push(target),
+                None => break,
+            }
+        }
+        path
+    }
+}
+
+/// The beams leaving the last row pushed, with how many timelines put a beam
+/// in each column, advanced one row at a time.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Beams {
+    rows: usize,
+    active: BTreeMap<usize, u64>,
+    splits: usize,
+    exited: u64,
+    /// `exited` plus every active count, the part 2 answer so far.
+    timelines: u64,
+}
+
+impl Beams {
+    pub fn active(&self) -> &BTreeMap<usize, u64> {
+        &self.active
+    }
+}
+
+impl Online for Beams {
+    fn push_line(&mut self, line: &str) -> Result<(), String> {
+        if self.rows == 0 {
+            let manifold = Manifold::parse(line, DEFAULT_SPLITTERS).map_err(|e| e.to_string())?;
+            self.active = BTreeMap::from([(manifold.start, 1)]);
+            self.timelines = 1;
+            self.rows = 1;
+            return Ok(());
+        }
+        let row = Manifold::single_row(line.as_bytes(), self.rows, DEFAULT_SPLITTERS)
+            .map_err(|e| e.to_string())?;
+
+        // Build the next row aside so an overflow leaves the state as it was.
+        let mut next: BTreeMap<usize, u64> = BTreeMap::new();
+        let mut splits = self.splits;
+        let mut exited = self.exited;
+        let overflow = || {
+            Overflow {
+                type_name: u64::NAME,
+                suggestion: u64::WIDER,
+            }
+            .to_string()
+        };
+        for (&column, &count) in &self.active {
+            if row.is_splitter(0, column) {
+                splits += 1;
+            }
+            for target in row.step(0, column) {
+                let total = match target {
+                    Some(target) => next.entry(target).or_insert(0),
+                    None => &mut exited,
+                };
+                *total = total.checked_add(count).ok_or_else(overflow)?;
+            }
+        }
+        let timelines = next
+            .values()
+            .try_fold(exited, |total, &count| total.checked_add(count))
+            .ok_or_else(overflow)?;
+
+        self.active = next;
+        self.splits = splits;
+        self.exited = exited;
+        self.timelines = timelines;
+        self.rows += 1;
+        Ok(())
+    }
+
+    fn current_answer(&self, part: u32) -> Option<Answer> {
+        if self.rows == 0 {
+            return None;
+        }
+        match part {
+            1 => Some(Answer::from(self.splits as i64)),
+            2 => Some(Answer::from(self.timelines)),
+            _ => None,
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Beams::default();
+    }
+}
+
+pub fn simulate<C: Count>(input: &str) -> BeamField<C> {
+    let manifold = Manifold::parse(input, DEFAULT_SPLITTERS).unwrap_or_else(|e| panic!("{}", e));
+    manifold.simulate().unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn timelines<C: Count>(input: &str) -> C {
+    simulate::<C>(input)
+        .timelines()
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn frames(input: &str) -> Vec<Frame> {
+    let manifold = Manifold::parse(input, DEFAULT_SPLITTERS).unwrap_or_else(|e| panic!("{}", e));
+    manifold.frames(&manifold.beams())
+}
+
+/// The finished beam tree as a PNG, every cell drawn `scale` pixels wide.
+pub fn picture(input: &str, scale: usize) -> Vec<u8> {
+    let colors = CellColors {
+        background: Rgb(16, 16, 32),
+        cells: vec![
+            ('S', Rgb(240, 200, 64)),
+            ('^', Rgb(200, 80, 200)),
+            ('|', Rgb(80, 200, 240)),
+        ],
+    };
+    let frames = frames(input);
+    let last = frames.last().expect("a manifold has at least one row");
+    Indexed::from_grid(&last.grid, scale, &colors).to_png()
+}
+
+/// Start yellow, splitters magenta, beams cyan, empty space dimmed.
+pub fn palette(c: char) -> Option<u8> {
+    match c {
+        'S' => Some(33),
+        '|' => Some(36),
+        '.' => Some(90),
+        c if DEFAULT_SPLITTERS.contains(&(c as u8)) => Some(35),
+        _ => None,
+    }
+}
+
+pub fn part1(input: &str) -> i64 {
+    let manifold = Manifold::parse(input, DEFAULT_SPLITTERS).unwrap_or_else(|e| panic!("{}", e));
+    manifold.beams().splits as i64
+}
+
+pub fn part2(input: &str) -> u64 {
+    timelines::<u64>(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint::BigUint;
+    use crate::count::Mod;
+    use crate::differential::Differential;
+    use crate::generators;
+    use crate::memo::Memo;
+    use crate::utils::GridDisplay;
+
+    #[test]
+    fn test_beams() {
+        let mut beams = Beams::default();
+        assert_eq!(beams.current_answer(1), None);
+        assert!(beams.push_line("...").is_err());
+        beams.push_line(".S.").unwrap();
+        beams.push_line(".^.").unwrap();
+        assert!(beams.push_line(".x.").is_err());
+        assert_eq!(beams.active(), &BTreeMap::from([(0, 1), (2, 1)]));
+        assert_eq!(beams.current_answer(2), Some(Answer::from(2u64)));
+
+        // Every splitter row doubles the timelines; the 64th row's total no
+        // longer fits even though each column's count still does.
+        let width = 129;
+        let mut beams = Beams::default();
+        beams
+            .push_line(&format!("{}S{}", ".".repeat(64), ".".repeat(64)))
+            .unwrap();
+        for _ in 0..63 {
+            beams.push_line(&"^".repeat(width)).unwrap();
+        }
+        let before = beams.clone();
+        assert!(beams.push_line(&"^".repeat(width)).is_err());
+        assert_eq!(beams, before);
+        assert_eq!(beams.current_answer(2), Some(Answer::from(1u64 << 63)));
+    }
+
+    #[test]
+    fn test_frames() {
+        let input = include_str!("../../inputs/2025/day07_test.txt");
+        let frames = frames(input);
+        let manifold = Manifold::parse(input, DEFAULT_SPLITTERS).unwrap();
+        let field = manifold.simulate::<u64>().unwrap();
+        assert_eq!(frames.len(), input.lines().count());
+        assert_eq!(
+            GridDisplay(&frames.last().unwrap().grid).to_string(),
+            manifold.render(&field)
+        );
+        assert!(frames.last().unwrap().caption.ends_with("21 splits"));
+    }
+
+    #[test]
+    fn test_ragged_rows() {
+        let input = "..S\n.\n.^.\n";
+        let frames = frames(input);
+        assert_eq!(
+            GridDisplay(&frames.last().unwrap().grid).to_string(),
+            "..S\n.\n.^|\n"
+        );
+        assert!(!picture(input, 1).is_empty());
+    }
+
+    #[test]
+    fn test_picture_is_deterministic() {
+        let input = include_str!("../../inputs/2025/day07_test.txt");
+        let png = picture(input, 4);
+        assert_eq!(png, picture(input, 4));
+        assert_eq!(crate::utils::fnv1a(&png), 13086577764588241826);
+    }
+
+    #[test]
+    fn test_edges_and_glyphs() {
+        let input = "S..\n^..\n..v\n";
+        let manifold = Manifold::parse(input, b"^v").unwrap();
+        let field = manifold.simulate::<u64>().unwrap();
+        assert_eq!(field.splits, 1);
+        assert_eq!(field.exited, 1);
+        assert_eq!(field.timelines(), Ok(2));
+        assert_eq!(manifold.render(&field), "S..\n^|.\n.|v\n");
+        assert_eq!(
+            Manifold::parse(input, DEFAULT_SPLITTERS).err(),
+            Some(ManifoldError::UnexpectedByte {
+                row: 2,
+                column: 2,
+                found: b'v'
+            })
+        );
+    }
+
+    #[test]
+    fn test_timelines() {
+        let input = include_str!("../../inputs/2025/day07_test.txt");
+        let manifold = Manifold::parse(input, DEFAULT_SPLITTERS).unwrap();
+        let field = manifold.simulate::<usize>().unwrap();
+
+        let all = manifold.enumerate_timelines(usize::MAX);
+        assert_eq!(Ok(all.len()), field.timelines());
+        assert!(all.iter().all(|t| t.len() == manifold.rows.len()));
+
+        let mut rng = Rng::new(7);
+        let sampled = manifold.sample_timeline(&field, &mut rng);
+        assert!(all.contains(&sampled));
+    }
+
+    /// Part 2 as a memoized recursion rather than the fold in [`simulate`].
+    fn timelines_memoized<C: Count>(input: &str) -> C {
+        let manifold = Manifold::parse(input, DEFAULT_SPLITTERS).unwrap();
+        manifold.timelines_memoized(&mut Memo::new()).unwrap()
+    }
+
+    #[test]
+    fn test_memoized_matches_fold() {
+        let input = include_str!("../../inputs/2025/day07_test.txt");
+        assert_eq!(timelines_memoized::<u64>(input), part2(input));
+        let manifold = Manifold::parse(input, DEFAULT_SPLITTERS).unwrap();
+        let mut memo = Memo::new();
+        manifold.timelines_memoized::<u64>(&mut memo).unwrap();
+        assert!(memo.stats().hits > 0);
+
+        Differential::new("day07_memoized", generators::day07).check(
+            |input| timelines::<BigUint>(input).to_string(),
+            |input| timelines_memoized::<BigUint>(input).to_string(),
+        );
+    }
+
+    #[test]
+    fn test_count_types() {
+        // Every row below the start doubles every timeline.
+        let width = 71;
+        let mut input = format!("{}S{}\n", ".".repeat(width / 2), ".".repeat(width / 2));
+        for _ in 0..34 {
+            input.push_str(&"^".repeat(width));
+            input.push('\n');
+        }
+        let manifold = Manifold::parse(&input, DEFAULT_SPLITTERS).unwrap();
+
+        let err = manifold
+            .simulate::<u32>()
+            .and_then(|field| field.timelines())
+            .unwrap_err();
+        assert_eq!(err.suggestion, Some("u64"));
+        // Splits don't depend on the count type, and need none at all.
+        let counted = manifold.simulate::<u64>().unwrap();
+        let beams = manifold.beams();
+        assert_eq!(beams.splits, counted.splits);
+        assert!(
+            beams
+                .rows
+                .iter()
+                .zip(&counted.rows)
+                .all(|(a, b)| a.keys().eq(b.keys()))
+        );
+        assert_eq!(counted.timelines(), Ok(1 << 34));
+        assert_eq!(
+            manifold.simulate::<BigUint>().unwrap().timelines().unwrap(),
+            BigUint::from(1u64 << 34)
+        );
+        assert_eq!(
+            manifold
+                .simulate::<Mod<1_000>>()
+                .unwrap()
+                .timelines()
+                .unwrap(),
+            Mod::new((1 << 34) % 1_000)
+        );
+    }
+}
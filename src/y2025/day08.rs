@@ -0,0 +1,828 @@
+use crate::explain::Trace;
+use crate::image::{Indexed, Rgb};
+use crate::repl::{Session, arg};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Coordinate {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseCoordinateError {
+    Int(ParseIntError),
+    FieldCount(usize),
+    /// A coordinate that failed to parse, with its 1-based line number.
+    Line(usize, Box<ParseCoordinateError>),
+}
+
+impl From<ParseIntError> for ParseCoordinateError {
+    fn from(e: ParseIntError) -> Self {
+        ParseCoordinateError::Int(e)
+    }
+}
+
+impl fmt::Display for ParseCoordinateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCoordinateError::Int(e) => write!(f, "invalid coordinate: {}", e),
+            ParseCoordinateError::FieldCount(n) => write!(f, "expected 3 fields, found {}", n),
+            ParseCoordinateError::Line(line, e) => write!(f, "line {}: {}", line, e),
+        }
+    }
+}
+
+impl std::error::Error for ParseCoordinateError {}
+
+impl FromStr for Coordinate {
+    type Err = ParseCoordinateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Fields are i32 so that squared distances always fit in an i128.
+        let parts: Vec<i32> = s
+            .split(',')
+            .map(|n| n.trim().parse())
+            .collect::<Result<_, _>>()?;
+        let [x, y, z] = parts[..] else {
+            return Err(ParseCoordinateError::FieldCount(parts.len()));
+        };
+
+        Ok(Coordinate {
+            x: x as i64,
+            y: y as i64,
+            z: z as i64,
+        })
+    }
+}
+
+impl Coordinate {
+    pub fn distance_squared(&self, other: &Coordinate) -> i128 {
+        let dx = (self.x - other.x) as i128;
+        let dy = (self.y - other.y) as i128;
+        let dz = (self.z - other.z) as i128;
+        dx.pow(2) + dy.pow(2) + dz.pow(2)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Edge {
+    pub p1_index: usize,
+    pub p2_index: usize,
+    pub dist_sq: i128,
+}
+
+impl PartialOrd for Edge {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Edge {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.dist_sq.cmp(&other.dist_sq)
+    }
+}
+
+/// One coordinate per line. Fails on the first line that isn't one,
+/// including coordinates outside the `i32` range.
+pub fn parse_coordinates(input: &str) -> Result<Vec<Coordinate>, ParseCoordinateError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.parse()
+                .map_err(|e| ParseCoordinateError::Line(i + 1, Box::new(e)))
+        })
+        .collect()
+}
+
+fn coordinates(input: &str) -> Vec<Coordinate> {
+    parse_coordinates(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn generate_sorted_edges(coords: &[Coordinate]) -> Vec<Edge> {
+    let mut edges = Vec::with_capacity(coords.len().pow(2));
+    for i in 0..coords.len() {
+        for j in (i + 1)..coords.len() {
+            edges.push(Edge {
+                p1_index: i,
+                p2_index: j,
+                dist_sq: coords[i].distance_squared(&coords[j]),
+            });
+        }
+    }
+    edges.sort_unstable();
+    edges
+}
+
+/// Which junction boxes are wired together, as a disjoint-set forest with
+/// union by size and path halving.
+struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    /// Number of separate circuits.
+    count: usize,
+}
+
+impl DisjointSet {
+    /// Every box in a circuit of its own.
+    fn new(num_points: usize) -> Self {
+        DisjointSet {
+            parent: (0..num_points).collect(),
+            size: vec![1; num_points],
+            count: num_points,
+        }
+    }
+
+    /// The box standing for the circuit `i` is in.
+    fn find(&mut self, mut i: usize) -> usize {
+        while self.parent[i] != i {
+            self.parent[i] = self.parent[self.parent[i]];
+            i = self.parent[i];
+        }
+        i
+    }
+
+    /// Joins the circuits of `a` and `b`, returning false if they were
+    /// already one.
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        let (big, small) = if self.size[a] >= self.size[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parent[small] = big;
+        self.size[big] += self.size[small];
+        self.count -= 1;
+        true
+    }
+}
+
+/// The circuit every junction box is in once `edges` are connected, named
+/// by the lowest index in it.
+pub fn circuits(num_points: usize, edges: &[Edge]) -> Vec<usize> {
+    let mut set = DisjointSet::new(num_points);
+    for edge in edges {
+        set.union(edge.p1_index, edge.p2_index);
+    }
+    // Boxes are visited in order, so the first one seen in a circuit is its
+    // lowest index.
+    let mut names = vec![None; num_points];
+    (0..num_points)
+        .map(|i| *names[set.find(i)].get_or_insert(i))
+        .collect()
+}
+
+/// Circuit names ordered largest first, ties broken by name.
+pub fn circuits_by_size(circuits: &[usize]) -> Vec<(usize, usize)> {
+    let mut sizes: BTreeMap<usize, usize> = BTreeMap::new();
+    for &circuit in circuits {
+        *sizes.entry(circuit).or_default() += 1;
+    }
+    let mut ranked: Vec<(usize, usize)> = sizes.into_iter().collect();
+    ranked.sort_by_key(|&(circuit, size)| (Reverse(size), circuit));
+    ranked
+}
+
+const CIRCUIT_COLORS: [Rgb; 10] = [
+    Rgb(230, 25, 75),
+    Rgb(60, 180, 75),
+    Rgb(255, 225, 25),
+    Rgb(0, 130, 200),
+    Rgb(245, 130, 48),
+    Rgb(145, 30, 180),
+    Rgb(70, 240, 240),
+    Rgb(240, 50, 230),
+    Rgb(210, 245, 60),
+    Rgb(250, 190, 212),
+];
+
+/// Index into [`CIRCUIT_COLORS`] for each circuit large enough to get its
+/// own color: the largest ones with more than one box.
+fn circuit_colors(circuits: &[usize]) -> HashMap<usize, usize> {
+    circuits_by_size(circuits)
+        .into_iter()
+        .take(CIRCUIT_COLORS.len())
+        .filter(|&(_, size)| size > 1)
+        .enumerate()
+        .map(|(i, (circuit, _))| (circuit, i))
+        .collect()
+}
+
+/// The junction boxes projected onto the XY plane as a `size` pixel square
+/// PNG, after the first `connections` connections. The largest circuits get
+/// their own colors; smaller ones and lone boxes are grey.
+pub fn projection(input: &str, connections: usize, size: usize) -> Vec<u8> {
+    let coords = coordinates(input);
+    let edges = generate_sorted_edges(&coords);
+    let circuits = circuits(coords.len(), &edges[..connections.min(edges.len())]);
+
+    let color: HashMap<usize, u8> = circuit_colors(&circuits)
+        .into_iter()
+        .map(|(circuit, i)| (circuit, i as u8 + 2))
+        .collect();
+
+    let mut palette = vec![Rgb(16, 16, 16), Rgb(110, 110, 110)];
+    palette.extend(CIRCUIT_COLORS);
+    let mut image = Indexed::new(size, size, palette);
+    let Some(min_x) = coords.iter().map(|c| c.x).min() else {
+        return image.to_png();
+    };
+    let min_y = coords.iter().map(|c| c.y).min().unwrap();
+    let span = coords
+        .iter()
+        .map(|c| (c.x - min_x).max(c.y - min_y))
+        .max()
+        .unwrap()
+        .max(1) as i128;
+    let (margin, radius) = (8, 2);
+    let inner = size.saturating_sub(2 * margin + 1) as i128;
+    let scale = |v: i64| margin + (v as i128 * inner / span) as usize;
+
+    // Grey boxes first, so circuits are drawn on top of them.
+    let mut order: Vec<usize> = (0..coords.len()).collect();
+    order.sort_by_key(|&i| color.get(&circuits[i]).copied().unwrap_or(1));
+    for i in order {
+        let (x, y) = (scale(coords[i].x - min_x), scale(coords[i].y - min_y));
+        let c = color.get(&circuits[i]).copied().unwrap_or(1);
+        image.fill_rect(x - radius, y - radius, 2 * radius + 1, 2 * radius + 1, c);
+    }
+    image.to_png()
+}
+
+/// The edges Kruskal's algorithm keeps from `edges`, which must be sorted:
+/// every one that joins two circuits, until everything is connected.
+pub fn spanning_tree(num_points: usize, edges: &[Edge]) -> Vec<&Edge> {
+    let mut set = DisjointSet::new(num_points);
+    let mut tree = Vec::new();
+    for edge in edges {
+        if set.count <= 1 {
+            break;
+        }
+        if set.union(edge.p1_index, edge.p2_index) {
+            tree.push(edge);
+        }
+    }
+    tree
+}
+
+/// The junction boxes and their edges, kept for exploring how circuits form
+/// as connections are made.
+pub struct Explorer {
+    coords: Vec<Coordinate>,
+    edges: Vec<Edge>,
+    connections: usize,
+    circuits: Vec<usize>,
+}
+
+impl Explorer {
+    pub fn new(input: &str) -> Self {
+        let coords = coordinates(input);
+        let edges = generate_sorted_edges(&coords);
+        let circuits = (0..coords.len()).collect();
+        Explorer {
+            coords,
+            edges,
+            connections: 0,
+            circuits,
+        }
+    }
+
+    fn describe(&self, index: usize) -> String {
+        let c = &self.coords[index];
+        format!("box {} ({},{},{})", index, c.x, c.y, c.z)
+    }
+
+    fn circuit_size(&self, circuit: usize) -> usize {
+        self.circuits.iter().filter(|&&c| c == circuit).count()
+    }
+}
+
+impl Session for Explorer {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("connect N", "connect the N shortest edges (from scratch)"),
+            ("circuit-of I", "the circuit box I is in"),
+            ("sizes [K]", "the K largest circuits (default 10)"),
+            ("edge K", "the Kth shortest edge, counting from 1"),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "connect" => {
+                let n: usize = arg(args, 0, "N")?;
+                self.connections = n.min(self.edges.len());
+                self.circuits = circuits(self.coords.len(), &self.edges[..self.connections]);
+                let count = circuits_by_size(&self.circuits).len();
+                Ok(format!(
+                    "{} connections, {} circuits",
+                    self.connections, count
+                ))
+            }
+            "circuit-of" => {
+                let i: usize = arg(args, 0, "I")?;
+                if i >= self.coords.len() {
+                    return Err(format!("there are only {} boxes", self.coords.len()));
+                }
+                let circuit = self.circuits[i];
+                Ok(format!(
+                    "{} is in circuit {} with {} boxes after {} connections",
+                    self.describe(i),
+                    circuit,
+                    self.circuit_size(circuit),
+                    self.connections
+                ))
+            }
+            "sizes" => {
+                let k: usize = if args.is_empty() {
+                    10
+                } else {
+                    arg(args, 0, "K")?
+                };
+                let ranked = circuits_by_size(&self.circuits);
+                let mut lines: Vec<String> = ranked
+                    .iter()
+                    .take(k)
+                    .map(|(circuit, size)| format!("circuit {}: {} boxes", circuit, size))
+                    .collect();
+                let product: usize = ranked.iter().take(3).map(|(_, size)| size).product();
+                lines.push(format!(
+                    "{} circuits, three largest multiply to {}",
+                    ranked.len(),
+                    product
+                ));
+                Ok(lines.join("\n"))
+            }
+            "edge" => {
+                let k: usize = arg(args, 0, "K")?;
+                let Some(edge) = k.checked_sub(1).and_then(|i| self.edges.get(i)) else {
+                    return Err(format!("edges are numbered 1 to {}", self.edges.len()));
+                };
+                Ok(format!(
+                    "{} to {}, distance squared {}, {}",
+                    self.describe(edge.p1_index),
+                    self.describe(edge.p2_index),
+                    edge.dist_sq,
+                    if k <= self.connections {
+                        "connected"
+                    } else {
+                        "not connected yet"
+                    }
+                ))
+            }
+            _ => Err(format!("unknown command {:?}", command)),
+        }
+    }
+}
+
+/// What an SVG drawing is looking at the junction boxes from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Plane {
+    XY,
+    XZ,
+    YZ,
+    /// Isometric, after turning the boxes this many degrees about the z axis.
+    Isometric(f64),
+}
+
+impl FromStr for Plane {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "xy" => Ok(Plane::XY),
+            "xz" => Ok(Plane::XZ),
+            "yz" => Ok(Plane::YZ),
+            "iso" | "isometric" => Ok(Plane::Isometric(0.0)),
+            _ => Err(format!("unknown plane {:?}, expected xy, xz, yz or iso", s)),
+        }
+    }
+}
+
+impl Plane {
+    /// Where `c` lands on the drawing, with the second axis pointing up.
+    pub fn project(&self, c: &Coordinate) -> (f64, f64) {
+        let (x, y, z) = (c.x as f64, c.y as f64, c.z as f64);
+        match *self {
+            Plane::XY => (x, y),
+            Plane::XZ => (x, z),
+            Plane::YZ => (y, z),
+            Plane::Isometric(degrees) => {
+                let (sin, cos) = degrees.to_radians().sin_cos();
+                let (x, y) = (x * cos - y * sin, x * sin + y * cos);
+                let (sin30, cos30) = 30f64.to_radians().sin_cos();
+                ((x - y) * cos30, z - (x + y) * sin30)
+            }
+        }
+    }
+}
+
+/// Draws day 8 as SVG: the junction boxes projected onto a plane, colored by
+/// circuit after the first `connections` connections, with those
+/// connections or the whole spanning tree as lines and the three largest
+/// circuits labelled.
+pub struct CircuitSvg {
+    connections: usize,
+    plane: Plane,
+    spanning_tree: bool,
+    size: f64,
+}
+
+impl CircuitSvg {
+    pub fn new(connections: usize) -> Self {
+        CircuitSvg {
+            connections,
+            plane: Plane::XY,
+            spanning_tree: false,
+            size: 800.0,
+        }
+    }
+
+    pub fn plane(mut self, plane: Plane) -> Self {
+        self.plane = plane;
+        self
+    }
+
+    /// Draw the full minimum spanning tree instead of the first connections.
+    pub fn spanning_tree(mut self, spanning_tree: bool) -> Self {
+        self.spanning_tree = spanning_tree;
+        self
+    }
+
+    /// Width and height of the drawing.
+    pub fn size(mut self, size: f64) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn render(&self, input: &str) -> String {
+        let coords = coordinates(input);
+        let edges = generate_sorted_edges(&coords);
+        let connected = &edges[..self.connections.min(edges.len())];
+        let circuits = circuits(coords.len(), connected);
+        let colors = circuit_colors(&circuits);
+        let color = |i: usize| {
+            colors
+                .get(&circuits[i])
+                .map_or("#6e6e6e".to_string(), |&c| {
+                    let Rgb(r, g, b) = CIRCUIT_COLORS[c];
+                    format!("#{:02x}{:02x}{:02x}", r, g, b)
+                })
+        };
+
+        // Fit the projection into the drawing, keeping its aspect ratio and
+        // flipping the second axis so it points up.
+        let projected: Vec<(f64, f64)> = coords.iter().map(|c| self.plane.project(c)).collect();
+        let (min_u, max_u, min_v, max_v) = projected.iter().fold(
+            (f64::MAX, f64::MIN, f64::MAX, f64::MIN),
+            |(a, b, c, d), &(u, v)| (a.min(u), b.max(u), c.min(v), d.max(v)),
+        );
+        let margin = 40.0;
+        let scale = (self.size - 2.0 * margin) / (max_u - min_u).max(max_v - min_v).max(1.0);
+        let points: Vec<(f64, f64)> = projected
+            .iter()
+            .map(|&(u, v)| {
+                (
+                    margin + (u - min_u) * scale,
+                    self.size - margin - (v - min_v) * scale,
+                )
+            })
+            .collect();
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{0}\" viewBox=\"0 0 {0} {0}\">\n",
+            self.size
+        );
+        svg.push_str("<rect width=\"100%\" height=\"100%\" fill=\"#101010\"/>\n");
+
+        let drawn: Vec<&Edge> = if self.spanning_tree {
+            spanning_tree(coords.len(), &edges)
+        } else {
+            connected.iter().collect()
+        };
+        svg.push_str("<g stroke-width=\"1.5\" stroke-opacity=\"0.7\">\n");
+        for edge in drawn {
+            let ((x1, y1), (x2, y2)) = (points[edge.p1_index], points[edge.p2_index]);
+            svg.push_str(&format!(
+                "<line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\" stroke=\"{}\"/>\n",
+                x1,
+                y1,
+                x2,
+                y2,
+                color(edge.p1_index)
+            ));
+        }
+        svg.push_str("</g>\n<g>\n");
+        for (i, &(x, y)) in points.iter().enumerate() {
+            let c = coords[i];
+            svg.push_str(&format!(
+                "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"4\" fill=\"{}\"><title>{}: {},{},{}</title></circle>\n",
+                x,
+                y,
+                color(i),
+                i,
+                c.x,
+                c.y,
+                c.z
+            ));
+        }
+        svg.push_str("</g>\n");
+
+        // Label the circuits part 1 multiplies, beside their topmost box.
+        let largest: Vec<(usize, usize)> =
+            circuits_by_size(&circuits).into_iter().take(3).collect();
+        for (rank, &(circuit, size)) in largest.iter().enumerate() {
+            let members = (0..coords.len()).filter(|&i| circuits[i] == circuit);
+            let top = members
+                .min_by(|&a, &b| points[a].1.total_cmp(&points[b].1))
+                .unwrap();
+            let (x, y) = points[top];
+            svg.push_str(&format!(
+                "<text x=\"{:.1}\" y=\"{:.1}\" fill=\"{}\" font-family=\"monospace\" font-size=\"14\">#{} ({} boxes)</text>\n",
+                x + 6.0,
+                y - 6.0,
+                color(top),
+                rank + 1,
+                size
+            ));
+        }
+        let sizes: Vec<String> = largest.iter().map(|(_, size)| size.to_string()).collect();
+        let product: usize = largest.iter().map(|(_, size)| size).product();
+        svg.push_str(&format!(
+            "<text x=\"{:.1}\" y=\"24\" fill=\"#e0e0e0\" font-family=\"monospace\" font-size=\"16\">{} connections: {} = {}</text>\n",
+            margin,
+            self.connections,
+            sizes.join(" \u{d7} "),
+            product
+        ));
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+pub fn part1(input: &str, x: usize) -> usize {
+    part1_traced(input, x, &mut Trace::off())
+}
+
+/// [`part1`], noting every connection made and the largest circuits.
+pub fn part1_traced(input: &str, x: usize, trace: &mut Trace) -> usize {
+    let coords = coordinates(input);
+
+    let edges = generate_sorted_edges(&coords);
+
+    let mut adjacency_list: HashMap<usize, Vec<usize>> = HashMap::new();
+
+    for edge in edges.iter().take(x) {
+        trace.note(|| {
+            format!(
+                "connect {} and {} (distance squared {})",
+                edge.p1_index, edge.p2_index, edge.dist_sq
+            )
+        });
+        adjacency_list
+            .entry(edge.p1_index)
+            .or_default()
+            .push(edge.p2_index);
+        adjacency_list
+            .entry(edge.p2_index)
+            .or_default()
+            .push(edge.p1_index);
+    }
+
+    let mut visited: HashSet<usize> = HashSet::new();
+    let mut component_sizes: Vec<usize> = Vec::new();
+
+    for start_node in 0..coords.len() {
+        if visited.contains(&start_node) {
+            continue;
+        }
+
+        let mut current_component_size = 0;
+        let mut queue = vec![start_node];
+        visited.insert(start_node);
+
+        while let Some(node) = queue.pop() {
+            current_component_size += 1;
+
+            if let Some(neighbors) = adjacency_list.get(&node) {
+                for &neighbor in neighbors {
+                    if visited.insert(neighbor) {
+                        queue.push(neighbor);
+                    }
+                }
+            }
+        }
+        component_sizes.push(current_component_size);
+    }
+
+    component_sizes.sort_unstable_by(|a, b| b.cmp(a));
+    trace.note(|| {
+        format!(
+            "largest circuits: {:?}",
+            &component_sizes[..component_sizes.len().min(3)]
+        )
+    });
+    component_sizes.iter().take(3).product()
+}
+
+pub fn part2(input: &str) -> i64 {
+    part2_traced(input, &mut Trace::off())
+}
+
+/// [`part2`], noting every merge of two circuits in the order it happens.
+pub fn part2_traced(input: &str, trace: &mut Trace) -> i64 {
+    let coords = coordinates(input);
+    let edges = generate_sorted_edges(&coords);
+
+    let mut set = DisjointSet::new(coords.len());
+
+    for edge in edges {
+        if set.union(edge.p1_index, edge.p2_index) {
+            let clusters_remaining = set.count;
+            trace.note(|| {
+                let (a, b) = (&coords[edge.p1_index], &coords[edge.p2_index]);
+                format!(
+                    "merge box {} ({},{},{}) and box {} ({},{},{}): {} circuits left",
+                    edge.p1_index, a.x, a.y, a.z, edge.p2_index, b.x, b.y, b.z, clusters_remaining
+                )
+            });
+
+            if clusters_remaining == 1 {
+                return coords[edge.p1_index].x * coords[edge.p2_index].x;
+            }
+        }
+    }
+    0
+}
+
+/// Adds edges shortest first and checks connectivity from scratch after
+/// every one.
+#[cfg(test)]
+fn part2_reference(input: &str) -> i64 {
+    let coords = coordinates(input);
+    let mut adjacency_list: Vec<Vec<usize>> = vec![Vec::new(); coords.len()];
+
+    for edge in generate_sorted_edges(&coords) {
+        adjacency_list[edge.p1_index].push(edge.p2_index);
+        adjacency_list[edge.p2_index].push(edge.p1_index);
+
+        let mut visited = vec![false; coords.len()];
+        let mut queue = vec![0];
+        visited[0] = true;
+        while let Some(node) = queue.pop() {
+            for &neighbor in &adjacency_list[node] {
+                if !visited[neighbor] {
+                    visited[neighbor] = true;
+                    queue.push(neighbor);
+                }
+            }
+        }
+
+        if visited.iter().all(|&v| v) {
+            return coords[edge.p1_index].x * coords[edge.p2_index].x;
+        }
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::Differential;
+    use crate::generators;
+
+    #[test]
+    fn test_explorer() {
+        let input = include_str!("../../inputs/2025/day08_test.txt");
+        let mut explorer = Explorer::new(input);
+        assert_eq!(
+            explorer.run("connect", &["10"]).unwrap(),
+            "10 connections, 11 circuits"
+        );
+        assert!(explorer.run("sizes", &["3"]).unwrap().ends_with(&format!(
+            "11 circuits, three largest multiply to {}",
+            part1(input, 10)
+        )));
+        assert!(
+            explorer
+                .run("edge", &["1"])
+                .unwrap()
+                .ends_with(", connected")
+        );
+        assert!(
+            explorer
+                .run("edge", &["11"])
+                .unwrap()
+                .ends_with("not connected yet")
+        );
+        assert!(explorer.run("edge", &["0"]).is_err());
+        let circuit = explorer.run("circuit-of", &["0"]).unwrap();
+        assert!(circuit.starts_with("box 0 (162,817,812) is in circuit 0 with 4 boxes"));
+    }
+
+    #[test]
+    fn test_circuits() {
+        let input = include_str!("../../inputs/2025/day08_test.txt");
+        let coords = coordinates(input);
+        let edges = generate_sorted_edges(&coords);
+        let circuits = circuits(coords.len(), &edges[..10]);
+        let sizes: Vec<usize> = circuits_by_size(&circuits)
+            .iter()
+            .map(|&(_, size)| size)
+            .collect();
+        assert_eq!(sizes.iter().take(3).product::<usize>(), part1(input, 10));
+        assert_eq!(sizes.iter().sum::<usize>(), coords.len());
+        // Every circuit is named by its lowest box.
+        assert!(
+            circuits
+                .iter()
+                .enumerate()
+                .all(|(i, &c)| c <= i && circuits[c] == c)
+        );
+    }
+
+    #[test]
+    fn test_projection_is_deterministic() {
+        let input = include_str!("../../inputs/2025/day08_test.txt");
+        let png = projection(input, 10, 128);
+        assert_eq!(png, projection(input, 10, 128));
+        assert_eq!(crate::utils::fnv1a(&png), 16100307514088670857);
+    }
+
+    #[test]
+    fn test_spanning_tree() {
+        let input = include_str!("../../inputs/2025/day08_test.txt");
+        let coords = coordinates(input);
+        let edges = generate_sorted_edges(&coords);
+        let tree = spanning_tree(coords.len(), &edges);
+        assert_eq!(tree.len(), coords.len() - 1);
+        let last = tree.last().unwrap();
+        assert_eq!(
+            coords[last.p1_index].x * coords[last.p2_index].x,
+            part2(input)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_coordinates("1,2,3\n4,5,99999999999\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2: invalid coordinate: number too large to fit in target type"
+        );
+        assert_eq!(
+            parse_coordinates("1,2,3\n4,5\n"),
+            Err(ParseCoordinateError::Line(
+                2,
+                Box::new(ParseCoordinateError::FieldCount(2))
+            ))
+        );
+        assert_eq!(parse_coordinates("1,2,3\n-4, 5,6\n").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_svg() {
+        let input = include_str!("../../inputs/2025/day08_test.txt");
+        let svg = CircuitSvg::new(10).render(input);
+        assert_eq!(svg.matches("<circle").count(), 20);
+        assert_eq!(svg.matches("<line").count(), 10);
+        assert!(svg.contains("10 connections: 5 \u{d7} 4 \u{d7} 2 = 40"));
+        assert!(svg.contains("#1 (5 boxes)"));
+        assert_eq!(svg, CircuitSvg::new(10).render(input));
+
+        let tree = CircuitSvg::new(10)
+            .plane("iso".parse().unwrap())
+            .spanning_tree(true)
+            .render(input);
+        assert_eq!(tree.matches("<line").count(), 19);
+        assert_ne!(tree, svg);
+    }
+
+    #[test]
+    fn test_planes() {
+        let c = Coordinate { x: 1, y: 2, z: 3 };
+        assert_eq!(Plane::XZ.project(&c), (1.0, 3.0));
+        assert_eq!("YZ".parse::<Plane>().unwrap().project(&c), (2.0, 3.0));
+        assert!("xw".parse::<Plane>().is_err());
+        let (u, v) = Plane::Isometric(90.0).project(&c);
+        let (u0, v0) = Plane::Isometric(0.0).project(&Coordinate { x: -2, y: 1, z: 3 });
+        assert!((u - u0).abs() < 1e-9 && (v - v0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_part2_matches_reference() {
+        Differential::new("day08_part2", generators::day08).check(part2_reference, part2);
+    }
+}
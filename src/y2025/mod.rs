@@ -0,0 +1,419 @@
+//! Advent of Code 2025, which runs for 12 days.
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+
+use crate::bigint::BigUint;
+use crate::explain::Trace;
+use crate::generators;
+use crate::online::Online;
+use crate::registry::{Answer, Params, param};
+use crate::repl::Session;
+use crate::rng::Rng;
+use crate::viz::{Frame, Palette};
+use std::io::{self, BufRead};
+
+/// Solves one part of one day, or returns `None` if there is no such day or
+/// part.
+pub fn solve(day: u32, part: u32, input: &str, params: &Params) -> Option<Answer> {
+    let answer = match (day, part) {
+        (3, 1 | 2) if exact(params) => {
+            let batteries = if part == 1 { 2 } else { 12 };
+            Answer::from(
+                day03::sum_banks_exact(input, batteries).unwrap_or_else(|e| panic!("{}", e)),
+            )
+        }
+        (6, 1 | 2) if exact(params) => {
+            let reading = if part == 1 {
+                day06::Reading::Rows
+            } else {
+                day06::Reading::Columns
+            };
+            let result = day06::solve_exact(input, reading, day06_cells(params));
+            Answer::from(result.unwrap_or_else(|e| panic!("{}", e)))
+        }
+        (7, 2) if exact(params) => Answer::from(day07::timelines::<BigUint>(input)),
+        (1, 1) => Answer::from(day01::part1(input)),
+        (1, 2) => Answer::from(day01::part2(input)),
+        (2, 1) => Answer::from(day02::part1(input)),
+        (2, 2) => Answer::from(day02::part2(input)),
+        (3, 1) => Answer::from(day03::part1(input)),
+        (3, 2) => Answer::from(day03::part2(input)),
+        (4, 1) => Answer::from(day04::part1(input)),
+        (4, 2) => Answer::from(day04::part2(input)),
+        (5, 1) => Answer::from(day05::part1(input)),
+        (5, 2) => Answer::from(day05::part2(input)),
+        (6, 1 | 2) => {
+            let cells = day06_cells(params);
+            if part == 1 {
+                Answer::from(day06::part1_with(input, cells))
+            } else {
+                Answer::from(day06::part2_with(input, cells))
+            }
+        }
+        (7, 1) => Answer::from(day07::part1(input)),
+        (7, 2) => Answer::from(day07::part2(input)),
+        (8, 1) => Answer::from(day08::part1(input, param(params, "connections", 1000))),
+        (8, 2) => Answer::from(day08::part2(input)),
+        _ => return None,
+    };
+    Some(answer)
+}
+
+/// Like [`solve`], noting the steps behind the answer on `trace`, for the
+/// days that can explain themselves: 1, 2, 3, 6 and 8.
+pub fn explain(
+    day: u32,
+    part: u32,
+    input: &str,
+    params: &Params,
+    trace: &mut Trace,
+) -> Option<Answer> {
+    let answer = match (day, part) {
+        (1, 1) => Answer::from(day01::part1_traced(input, trace)),
+        (1, 2) => Answer::from(day01::part2_traced(input, trace)),
+        (2, 1) => Answer::from(day02::sum_invalid_traced(input, day02::is_twice, trace)),
+        (2, 2) => Answer::from(day02::sum_invalid_traced(input, day02::is_periodic, trace)),
+        (3, 1) => Answer::from(
+            day03::sum_banks_traced(input, 2, trace).unwrap_or_else(|e| panic!("{}", e)),
+        ),
+        (3, 2) => Answer::from(
+            day03::sum_banks_traced(input, 12, trace).unwrap_or_else(|e| panic!("{}", e)),
+        ),
+        (6, 1 | 2) => {
+            let reading = if part == 1 {
+                day06::Reading::Rows
+            } else {
+                day06::Reading::Columns
+            };
+            let result = day06::solve_traced(input, reading, day06_cells(params), trace);
+            Answer::from(result.unwrap_or_else(|e| panic!("{}", e)))
+        }
+        (8, 1) => Answer::from(day08::part1_traced(
+            input,
+            param(params, "connections", 1000),
+            trace,
+        )),
+        (8, 2) => Answer::from(day08::part2_traced(input, trace)),
+        _ => return None,
+    };
+    Some(answer)
+}
+
+/// Whether `exact=true` asks for answers in big integers rather than
+/// `i64`, for the days that can give them: 3, 6 and 7.
+fn exact(params: &Params) -> bool {
+    param(params, "exact", false)
+}
+
+/// Day 6's `cells` param: `numbers` (the default) or `expressions`.
+fn day06_cells(params: &Params) -> day06::Cells {
+    match params.get("cells").map(String::as_str) {
+        None | Some("numbers") => day06::Cells::Numbers,
+        Some("expressions") => day06::Cells::Expressions,
+        Some(other) => panic!("invalid value {:?} for cells", other),
+    }
+}
+
+/// Like [`solve`], but reading the input from `reader` with memory bounded
+/// by its longest line, for the days that allow it: 1, 2, 3 and 5.
+pub fn solve_stream(
+    day: u32,
+    part: u32,
+    reader: &mut dyn BufRead,
+    _params: &Params,
+) -> Option<io::Result<Answer>> {
+    let answer = match (day, part) {
+        (1, 1) => day01::part1_stream(reader).map(Answer::from),
+        (1, 2) => day01::part2_stream(reader).map(Answer::from),
+        (2, 1) => day02::part1_stream(reader).map(Answer::from),
+        (2, 2) => day02::part2_stream(reader).map(Answer::from),
+        (3, 1) => day03::part1_stream(reader).map(Answer::from),
+        (3, 2) => day03::part2_stream(reader).map(Answer::from),
+        (5, 1) => day05::part1_stream(reader).map(Answer::from),
+        (5, 2) => day05::part2_stream(reader).map(Answer::from),
+        _ => return None,
+    };
+    Some(answer)
+}
+
+/// A fresh line-at-a-time solver for days 1, 3, 5 and 7.
+pub fn online(day: u32) -> Option<Box<dyn Online>> {
+    match day {
+        1 => Some(Box::new(day01::Dial::new())),
+        3 => Some(Box::new(day03::Banks::default())),
+        5 => Some(Box::new(day05::Inventory::default())),
+        7 => Some(Box::new(day07::Beams::default())),
+        _ => None,
+    }
+}
+
+/// A command session over `input` for days 4, 5 and 8.
+pub fn repl(day: u32, input: &str) -> Option<Box<dyn Session>> {
+    match day {
+        4 => Some(Box::new(day04::Explorer::new(input))),
+        5 => Some(Box::new(day05::Explorer::new(input))),
+        8 => Some(Box::new(day08::Explorer::new(input))),
+        _ => None,
+    }
+}
+
+/// Frames and colors for the days that have a visualization.
+pub fn visualize(day: u32, input: &str) -> Option<(Vec<Frame>, Palette)> {
+    match day {
+        4 => Some((day04::frames(input), day04::palette)),
+        7 => Some((day07::frames(input), day07::palette)),
+        _ => None,
+    }
+}
+
+/// An image of the day's solution, PNG or GIF, as its file extension and
+/// bytes. `params` can set `scale` (pixels per cell), `delay` (hundredths of
+/// a second per frame), `size` (pixels across) and day 8's `connections`.
+pub fn image(day: u32, input: &str, params: &Params) -> Option<(&'static str, Vec<u8>)> {
+    match day {
+        4 => Some((
+            "gif",
+            day04::animation(input, param(params, "scale", 4), param(params, "delay", 20)),
+        )),
+        7 => Some(("png", day07::picture(input, param(params, "scale", 4)))),
+        8 => Some((
+            "png",
+            day08::projection(
+                input,
+                param(params, "connections", 1000),
+                param(params, "size", 512),
+            ),
+        )),
+        _ => None,
+    }
+}
+
+/// Day 8's circuits as SVG. `plane` picks the projection, with `angle`
+/// turning the isometric one, and `tree` draws only the spanning tree.
+pub fn svg(day: u32, input: &str, params: &Params) -> Option<String> {
+    match day {
+        8 => {
+            let plane = match param(params, "plane", day08::Plane::XY) {
+                day08::Plane::Isometric(_) => day08::Plane::Isometric(param(params, "angle", 0.0)),
+                plane => plane,
+            };
+            let svg = day08::CircuitSvg::new(param(params, "connections", 1000))
+                .plane(plane)
+                .spanning_tree(param(params, "tree", false))
+                .size(param(params, "size", 800.0))
+                .render(input);
+            Some(svg)
+        }
+        _ => None,
+    }
+}
+
+pub fn generate(day: u32, size: usize, rng: &mut Rng) -> Option<String> {
+    generators::generate(day, size, rng)
+}
+
+/// Parses `input` the way day `day` does and, when it is a valid puzzle
+/// input, solves it. Rejecting malformed input is fine; panicking on anything
+/// is a bug. Shared by the fuzz targets and their regression tests.
+pub fn fuzz(day: u32, input: &str) {
+    match day {
+        1 if day01::parse_rotations(input).is_ok() => {
+            day01::part1(input);
+            day01::part2(input);
+        }
+        2 => {
+            // Both parts visit every ID, so keep the ranges small enough to
+            // finish quickly.
+            if let Ok(ranges) = day02::parse_ranges(input)
+                && ranges
+                    .iter()
+                    .map(|r| r.end().saturating_sub(*r.start()).saturating_add(1).max(0) as u64)
+                    .fold(0u64, u64::saturating_add)
+                    <= 10_000
+            {
+                day02::part1(input);
+                day02::part2(input);
+            }
+        }
+        3 => {
+            for batteries in [2, 12] {
+                let _ = day03::sum_banks(input, batteries);
+            }
+        }
+        4 => {
+            day04::part1(input);
+            day04::part2(input);
+        }
+        5 => {
+            day05::part1(input);
+            day05::part2(input);
+        }
+        6 => {
+            for reading in [day06::Reading::Rows, day06::Reading::Columns] {
+                for cells in [day06::Cells::Numbers, day06::Cells::Expressions] {
+                    let _ = day06::solve(input, reading, cells);
+                }
+            }
+        }
+        7 => {
+            if let Ok(manifold) = day07::Manifold::parse(input, day07::DEFAULT_SPLITTERS) {
+                if let Ok(field) = manifold.simulate::<u64>() {
+                    let _ = field.timelines();
+                    manifold.render(&field);
+                }
+                manifold.enumerate_timelines(16);
+            }
+        }
+        8 if day08::parse_coordinates(input).is_ok() => {
+            day08::part1(input, 10);
+            day08::part2(input);
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::Differential;
+    use crate::rng::Rng;
+    use std::fs;
+    use std::path::Path;
+
+    #[test]
+    fn test_explain_matches_solve() {
+        let fixtures = crate::fixtures::load(2025).unwrap_or_else(|e| panic!("{}", e));
+        let dir = crate::registry::year_dir(2025);
+        for fixture in &fixtures {
+            let input = fixture.input(&dir).unwrap();
+            for part in 1..=2 {
+                let mut trace = Trace::new(5);
+                let Some(explained) =
+                    explain(fixture.day, part, &input, &fixture.params, &mut trace)
+                else {
+                    continue;
+                };
+                assert_eq!(
+                    Some(explained),
+                    solve(fixture.day, part, &input, &fixture.params)
+                );
+                assert!(
+                    !trace.lines().is_empty(),
+                    "{} part {}",
+                    fixture.name(),
+                    part
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_exact_matches_solve() {
+        let fixtures = crate::fixtures::load(2025).unwrap_or_else(|e| panic!("{}", e));
+        let dir = crate::registry::year_dir(2025);
+        for fixture in fixtures.iter().filter(|f| [3, 6, 7].contains(&f.day)) {
+            let input = fixture.input(&dir).unwrap();
+            let mut params = fixture.params.clone();
+            params.insert("exact".to_string(), "true".to_string());
+            for part in 1..=2 {
+                let exact = solve(fixture.day, part, &input, &params).unwrap();
+                assert_eq!(
+                    Some(exact),
+                    solve(fixture.day, part, &input, &fixture.params),
+                    "{} part {}",
+                    fixture.name(),
+                    part
+                );
+            }
+        }
+    }
+
+    /// The ways of solving a day besides [`solve`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Route {
+        Stream,
+        Online,
+        Exact,
+    }
+
+    /// Which routes each day has.
+    const ROUTES: &[(u32, &[Route])] = &[
+        (1, &[Route::Stream, Route::Online]),
+        (2, &[Route::Stream]),
+        (3, &[Route::Stream, Route::Online, Route::Exact]),
+        (5, &[Route::Stream, Route::Online]),
+        (6, &[Route::Exact]),
+        (7, &[Route::Online, Route::Exact]),
+    ];
+
+    fn routes(day: u32) -> &'static [Route] {
+        ROUTES
+            .iter()
+            .find(|&&(d, _)| d == day)
+            .map_or(&[], |&(_, routes)| routes)
+    }
+
+    fn answers(day: u32, input: &str, params: &Params) -> [Option<String>; 2] {
+        [1, 2].map(|part| solve(day, part, input, params).map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn test_routes_are_listed() {
+        for day in generators::DAYS {
+            let streams = solve_stream(day, 1, &mut "".as_bytes(), &Params::new()).is_some();
+            assert_eq!(streams, routes(day).contains(&Route::Stream), "day {}", day);
+            let online = online(day).is_some();
+            assert_eq!(online, routes(day).contains(&Route::Online), "day {}", day);
+        }
+    }
+
+    #[test]
+    fn test_routes_match_solve() {
+        let exact_params = Params::from([("exact".to_string(), "true".to_string())]);
+        for &(day, routes) in ROUTES {
+            for &route in routes {
+                let name = format!("day{:02}_{:?}", day, route).to_lowercase();
+                let generate = |size, rng: &mut Rng| generators::generate(day, size, rng).unwrap();
+                let answer = |input: &str| match route {
+                    Route::Stream => [1, 2].map(|part| {
+                        let answer = solve_stream(day, part, &mut input.as_bytes(), &Params::new());
+                        answer.map(|a| a.unwrap().to_string())
+                    }),
+                    Route::Online => {
+                        crate::online::answers(&mut *online(day).unwrap(), input).unwrap()
+                    }
+                    Route::Exact => answers(day, input, &exact_params),
+                };
+                Differential::new(&name, generate)
+                    .separator(if day == 2 { ',' } else { '\n' })
+                    .check(|input| answers(day, input, &Params::new()), answer);
+            }
+        }
+    }
+
+    /// Replays every crash the fuzzers have found, saved as
+    /// `inputs/regressions/fuzz/dayNN_<what>.txt`.
+    #[test]
+    fn test_fuzz_regressions() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs/regressions/fuzz");
+        let Ok(entries) = fs::read_dir(&dir) else {
+            return;
+        };
+        for entry in entries {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            let day: u32 = name
+                .get(3..5)
+                .and_then(|n| n.parse().ok())
+                .unwrap_or_else(|| panic!("{} is not named dayNN_*.txt", name));
+            fuzz(day, &fs::read_to_string(&path).unwrap());
+        }
+    }
+}